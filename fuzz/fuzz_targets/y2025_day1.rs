@@ -0,0 +1,6 @@
+#![no_main]
+
+use aoc2025::y2025::day1::Day1;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| aoc2025_fuzz::solve::<Day1>(input));
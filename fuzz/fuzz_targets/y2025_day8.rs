@@ -0,0 +1,6 @@
+#![no_main]
+
+use aoc2025::y2025::day8::Day8;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| aoc2025_fuzz::solve::<Day8>(input));
@@ -0,0 +1,6 @@
+#![no_main]
+
+use aoc2025::y2025::day9::Day9;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| aoc2025_fuzz::solve::<Day9>(input));
@@ -0,0 +1,19 @@
+//! Fuzz targets that feed arbitrary text to each day, which must return an error rather than panic
+//! on any input it can't solve.
+//!
+//! Run one with `cargo +nightly fuzz run y2025_day7` from the root of the repository.
+
+use aoc2025::solution::Solution;
+
+/// Parses the input and solves every part, leaving any panic to the fuzzer.
+/// Unlike the registry, this doesn't catch panics and turn them into errors.
+pub fn solve<T: Solution>(input: &str) {
+    let solution = T::default();
+    let Ok(parsed) = solution.parse(input) else {
+        return;
+    };
+    let _ = solution.part1(&parsed);
+    if T::HAS_PART2 {
+        let _ = solution.part2(&parsed);
+    }
+}
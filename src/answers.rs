@@ -0,0 +1,129 @@
+use anyhow::{Context, Result, bail};
+use aoc2025::solution::Part;
+use serde::{Deserialize, Deserializer};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Known answers, keyed by year, day, input file and then part:
+///
+/// ```toml
+/// [2025.day8."inputs/2025/day8.txt"]
+/// part1 = 123456
+/// part2 = "abc"
+///
+/// [2021.day13."inputs/2021/day13.txt"]
+/// part1 = '''
+/// #..#
+/// ####
+/// '''
+/// ```
+///
+/// A drawing is written as a multi-line string, and the newline before its closing quotes is ignored.
+pub struct Answers {
+    days: BTreeMap<(u16, usize), BTreeMap<PathBuf, PartAnswers>>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PartAnswers {
+    #[serde(default, deserialize_with = "answer")]
+    part1: Option<String>,
+    #[serde(default, deserialize_with = "answer")]
+    part2: Option<String>,
+}
+
+impl PartAnswers {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+fn answer<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    // numeric answers may be written without quotes
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Answer {
+        Text(String),
+        Number(i64),
+    }
+
+    let answer = match Answer::deserialize(deserializer)? {
+        Answer::Text(text) => text.strip_suffix('\n').unwrap_or(&text).to_string(),
+        Answer::Number(num) => num.to_string(),
+    };
+    Ok(Some(answer))
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read answers file {}", path.display()))?;
+        Self::parse(&contents)
+            .with_context(|| format!("Failed to parse answers file {}", path.display()))
+    }
+
+    fn parse(contents: &str) -> Result<Self> {
+        let raw: BTreeMap<String, BTreeMap<String, BTreeMap<PathBuf, PartAnswers>>> =
+            toml::from_str(contents)?;
+        let mut days = BTreeMap::new();
+        let mut keys = BTreeMap::new();
+        for (year_key, year_days) in raw {
+            let year = year_key
+                .parse()
+                .with_context(|| format!("expected a year like '2025', found '{}'", year_key))?;
+            for (key, inputs) in year_days {
+                let day = key
+                    .strip_prefix("day")
+                    .and_then(|day| day.parse().ok())
+                    .with_context(|| format!("expected a key like 'day1', found '{}'", key))?;
+                // keys like 'day01' and 'day1' name the same day, and neither may hide the other
+                if let Some(first) = keys.insert((year, day), key.clone()) {
+                    bail!("'{}' and '{}' of {} are the same day", first, key, year);
+                }
+                days.insert((year, day), inputs);
+            }
+        }
+        Ok(Self { days })
+    }
+
+    /// The inputs with known answers for a day
+    pub fn for_day(&self, year: u16, day: usize) -> impl Iterator<Item = (&Path, &PartAnswers)> {
+        self.days
+            .get(&(year, day))
+            .into_iter()
+            .flatten()
+            .map(|(path, answers)| (path.as_path(), answers))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drawings_lose_the_newline_before_their_closing_quotes() {
+        let answers: PartAnswers =
+            toml::from_str("part1 = '''\n#..#\n####\n'''\npart2 = 7\n").unwrap();
+        assert_eq!(answers.get(Part::One), Some("#..#\n####"));
+        assert_eq!(answers.get(Part::Two), Some("7"));
+        // only the last newline is left out
+        let answers: PartAnswers = toml::from_str("part1 = \"#\\n\\n\"").unwrap();
+        assert_eq!(answers.get(Part::One), Some("#\n"));
+    }
+
+    #[test]
+    fn a_day_listed_under_two_keys_is_an_error() {
+        let answers =
+            "[2025.day1]\n'a.txt' = { part1 = 1 }\n[2025.day01]\n'b.txt' = { part1 = 2 }\n";
+        let err = Answers::parse(answers).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "'day01' and 'day1' of 2025 are the same day"
+        );
+        assert!(Answers::parse(&answers.replace("day01", "day2")).is_ok());
+    }
+}
@@ -0,0 +1,207 @@
+use crate::cli::BenchArgs;
+use crate::input::Input;
+use crate::report::format_size;
+use crate::table::Table;
+use anyhow::{Context, Result, anyhow, bail, ensure};
+use aoc2025::memory::AllocStats;
+use aoc2025::registry::{PartResult, Puzzle, Solver};
+use aoc2025::solution::Part;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+struct Stats {
+    min: Duration,
+    median: Duration,
+    mean: Duration,
+    std_dev: Duration,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+        let mean = samples.iter().sum::<Duration>() / n as u32;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / (n.max(2) - 1) as f64;
+        Self {
+            min: samples[0],
+            median,
+            mean,
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Median timings in nanoseconds, keyed by year, day and part, in the same
+/// `YYYY`/`dayN`/`partN` layout as the answers file
+#[derive(Serialize, Deserialize, Default)]
+struct Baseline(BTreeMap<String, BTreeMap<String, BTreeMap<String, u64>>>);
+
+impl Baseline {
+    fn load(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read baseline {}", path.display()))?;
+        toml::from_str(&contents)
+            .with_context(|| format!("Failed to parse baseline {}", path.display()))
+    }
+
+    fn save(&self, path: &Path) -> Result<()> {
+        let contents = toml::to_string(self)?;
+        fs::write(path, contents)
+            .with_context(|| format!("Failed to write baseline {}", path.display()))
+    }
+
+    fn get(&self, puzzle: &Puzzle, part: Part) -> Option<Duration> {
+        let nanos = self
+            .0
+            .get(&puzzle.year.to_string())?
+            .get(&format!("day{}", puzzle.day))?
+            .get(&format!("part{}", part))?;
+        Some(Duration::from_nanos(*nanos))
+    }
+
+    fn insert(&mut self, puzzle: &Puzzle, part: Part, median: Duration) {
+        self.0
+            .entry(puzzle.year.to_string())
+            .or_default()
+            .entry(format!("day{}", puzzle.day))
+            .or_default()
+            .insert(format!("part{}", part), median.as_nanos() as u64);
+    }
+}
+
+/// Benchmarks the selected parts, returning whether all of them could be measured
+pub fn bench(args: &BenchArgs) -> Result<bool> {
+    ensure!(args.runs > 0, "--runs must be at least 1");
+    let baseline = args.baseline.as_deref().map(Baseline::load).transpose()?;
+    // the new timings are merged into the saved ones, so benchmarking some days keeps the others
+    let mut timings = match &args.save_baseline {
+        Some(path) if path.exists() => Baseline::load(path)?,
+        _ => Baseline::default(),
+    };
+    // allocations don't vary between runs, so they are only shown once
+    let memory_columns = match cfg!(feature = "count-allocations") {
+        true => &["Allocations", "Allocated", "Peak"][..],
+        false => &[],
+    };
+    let mut table = Table::new(
+        [
+            "Day", "Part", "Min", "Median", "Mean", "Std dev", "Parse", "Solve", "Change",
+        ]
+        .into_iter()
+        .chain(memory_columns.iter().copied()),
+    );
+
+    let puzzles = crate::select(&args.selection)?;
+    crate::check_params(&puzzles, &args.params)?;
+    let mut failures = Vec::new();
+    for puzzle in puzzles {
+        let (day, solver) = (puzzle.day, puzzle.solver);
+        // like `run`, a day that can't be benchmarked is reported without stopping the others
+        let input = Input::from_default_path(puzzle.year, day);
+        for part in solver.parts() {
+            if !part.is_selected(args.selection.part) {
+                continue;
+            }
+            let measured = match &input {
+                Ok(input) => {
+                    eprintln!("Benchmarking day {} part {}...", day, part);
+                    measure(solver, &input.contents, part, args)
+                }
+                Err(err) => Err(anyhow!("{:#}", err)),
+            };
+            let (stats, parse, solve, memory) = match measured {
+                Ok(measured) => measured,
+                Err(err) => {
+                    table.push([day.to_string(), part.to_string(), "failed".to_string()]);
+                    failures.push((day, part, err));
+                    continue;
+                }
+            };
+            let change = baseline
+                .as_ref()
+                .and_then(|baseline| baseline.get(puzzle, part))
+                .map(|old| {
+                    let change = stats.median.as_secs_f64() / old.as_secs_f64() - 1.0;
+                    format!("{:+.1}%", change * 100.0)
+                })
+                .unwrap_or_default();
+            let memory = memory.map(|memory| {
+                [
+                    memory.allocations.to_string(),
+                    format_size(memory.bytes),
+                    format_size(memory.peak),
+                ]
+            });
+            table.push(
+                [
+                    day.to_string(),
+                    part.to_string(),
+                    format!("{:.2?}", stats.min),
+                    format!("{:.2?}", stats.median),
+                    format!("{:.2?}", stats.mean),
+                    format!("{:.2?}", stats.std_dev),
+                    format!("{:.2?}", parse.median),
+                    format!("{:.2?}", solve.median),
+                    change,
+                ]
+                .into_iter()
+                .chain(memory.into_iter().flatten()),
+            );
+            timings.insert(puzzle, part, stats.median);
+        }
+    }
+
+    print!("{}", table);
+    if !failures.is_empty() {
+        println!("{} parts failed:", failures.len());
+        for (day, part, err) in &failures {
+            println!("  Day {} part {}: {:#}", day, part, err);
+        }
+    }
+    if let Some(path) = &args.save_baseline {
+        timings.save(path)?;
+        println!("Saved baseline to {}", path.display());
+    }
+    Ok(failures.is_empty())
+}
+
+/// Times a part over the requested runs, returning the stats for the whole part,
+/// for parsing alone and for solving alone, and what the last run allocated
+fn measure(
+    solver: Solver,
+    input: &str,
+    part: Part,
+    args: &BenchArgs,
+) -> Result<(Stats, Stats, Stats, Option<AllocStats>)> {
+    let time_once = || -> Result<PartResult> {
+        let result = solver.solve(input, Some(part), &args.params).remove(0);
+        if let Err(err) = &result.answer {
+            bail!("{:#}", err);
+        }
+        Ok(result)
+    };
+    for _ in 0..args.warmup {
+        time_once()?;
+    }
+    let results: Vec<_> = (0..args.runs).map(|_| time_once()).collect::<Result<_>>()?;
+    let stats =
+        |elapsed: fn(&PartResult) -> Duration| Stats::new(results.iter().map(elapsed).collect());
+    Ok((
+        stats(PartResult::total_elapsed),
+        stats(|result| result.parse_elapsed),
+        stats(|result| result.elapsed),
+        results.last().and_then(|result| result.memory),
+    ))
+}
@@ -0,0 +1,445 @@
+use crate::limits::Limits;
+use crate::report::Format;
+use anyhow::{Context, Error, Result, ensure};
+use aoc2025::solution::{Param, Part};
+use clap::{Args, Parser, Subcommand};
+use itertools::Itertools;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+#[derive(Parser)]
+#[command(
+    version,
+    about = "Advent of Code solutions",
+    args_conflicts_with_subcommands = true
+)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    // running without a subcommand behaves like `run`
+    #[command(flatten)]
+    pub run: RunArgs,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Solve the selected days and print the answers
+    Run(RunArgs),
+    /// Time the selected days over many runs
+    Bench(BenchArgs),
+    /// Check the answers against a file of known answers
+    Verify(VerifyArgs),
+    /// List the days that have a solution and their parameters
+    List {
+        /// Only list this year's days
+        #[arg(short, long)]
+        year: Option<u16>,
+    },
+    /// Download puzzle inputs that haven't been downloaded yet
+    Fetch(FetchArgs),
+    /// Solve a part and submit the answer
+    Submit(SubmitArgs),
+    /// Re-run a day on its examples and input whenever they or the source change
+    Watch(WatchArgs),
+    /// Print a random input for a day, to stress-test or benchmark its solution
+    Generate(GenerateArgs),
+    /// Compare the selected days with their slow reference solutions on small random inputs
+    Crosscheck(CrosscheckArgs),
+    /// Add a day's module, input and example files from a template
+    New {
+        #[arg(value_parser = parse_day)]
+        day: usize,
+        /// The year to add the day to (the latest year if omitted)
+        #[arg(short, long)]
+        year: Option<u16>,
+        /// The puzzle's title, shown by `list`
+        #[arg(short, long, default_value = "")]
+        title: String,
+    },
+    /// Solve the selected parts of the input on stdin and print the results as JSON
+    #[command(hide = true)]
+    Worker {
+        year: u16,
+        day: usize,
+        #[arg(long)]
+        part: Option<Part>,
+        #[arg(long = "param")]
+        params: Vec<Param>,
+        /// The most memory in bytes the process may allocate
+        #[arg(long)]
+        max_memory: Option<u64>,
+    },
+}
+
+#[derive(Args)]
+pub struct RunArgs {
+    #[command(flatten)]
+    pub selection: Selection,
+
+    /// Read the puzzle input from these files instead of inputs/YYYY/dayN.txt;
+    /// `-` reads stdin and a directory means every file inside it
+    #[arg(short, long = "input", value_name = "PATH")]
+    pub inputs: Vec<PathBuf>,
+
+    /// Solve the examples from the puzzle descriptions instead of the real inputs
+    #[arg(short, long, conflicts_with = "inputs")]
+    pub example: bool,
+
+    /// Override a solver parameter, e.g. `num_connections=10` (`list` shows them all)
+    #[arg(long = "param", value_name = "NAME=VALUE")]
+    pub params: Vec<Param>,
+
+    /// How to print the results
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+
+    /// Solve days and parts on this many threads (0 uses every core)
+    #[arg(short, long, default_value_t = 1)]
+    pub jobs: usize,
+
+    #[command(flatten)]
+    pub limits: Limits,
+}
+
+#[derive(Args)]
+pub struct BenchArgs {
+    #[command(flatten)]
+    pub selection: Selection,
+
+    /// Override a solver parameter, e.g. `num_connections=10` (`list` shows them all)
+    #[arg(long = "param", value_name = "NAME=VALUE")]
+    pub params: Vec<Param>,
+
+    /// Untimed runs before measuring
+    #[arg(short, long, default_value_t = 3)]
+    pub warmup: usize,
+
+    /// Timed runs per part
+    #[arg(short, long, default_value_t = 20)]
+    pub runs: usize,
+
+    /// Compare against timings previously saved with --save-baseline
+    #[arg(short, long, value_name = "FILE")]
+    pub baseline: Option<PathBuf>,
+
+    /// Save the timings to this file, keeping those it has for the days not benchmarked
+    #[arg(short, long, value_name = "FILE")]
+    pub save_baseline: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct VerifyArgs {
+    #[command(flatten)]
+    pub selection: Selection,
+
+    /// File containing the known answers
+    #[arg(short, long, default_value = "answers.toml")]
+    pub answers: PathBuf,
+
+    /// Override a solver parameter, e.g. `num_connections=10` (`list` shows them all)
+    #[arg(long = "param", value_name = "NAME=VALUE")]
+    pub params: Vec<Param>,
+
+    #[command(flatten)]
+    pub limits: Limits,
+}
+
+#[derive(Args)]
+pub struct FetchArgs {
+    /// Days to download, e.g. `3` or `1..5` (every day with a solution if omitted)
+    pub days: Vec<DayRange>,
+
+    /// The event to download from (the latest year if omitted)
+    #[arg(short, long)]
+    pub year: Option<u16>,
+
+    #[command(flatten)]
+    pub site: Site,
+}
+
+#[derive(Args)]
+pub struct SubmitArgs {
+    #[arg(value_parser = parse_day)]
+    pub day: usize,
+    pub part: Part,
+
+    /// The event the day belongs to (the latest year if omitted)
+    #[arg(short, long)]
+    pub year: Option<u16>,
+
+    /// Override a solver parameter, e.g. `num_connections=10`
+    #[arg(long = "param", value_name = "NAME=VALUE")]
+    pub params: Vec<Param>,
+
+    /// File recording every submission, used to avoid resubmitting wrong answers
+    #[arg(long, value_name = "FILE", default_value = "submissions.toml")]
+    pub history: PathBuf,
+
+    #[command(flatten)]
+    pub site: Site,
+}
+
+#[derive(Args)]
+pub struct WatchArgs {
+    #[arg(value_parser = parse_day)]
+    pub day: usize,
+
+    /// The event the day belongs to (the latest year if omitted)
+    #[arg(short, long)]
+    pub year: Option<u16>,
+
+    /// Override a solver parameter, e.g. `num_connections=10`
+    #[arg(long = "param", value_name = "NAME=VALUE")]
+    pub params: Vec<Param>,
+
+    /// File containing the known answers for the real input
+    #[arg(short, long, default_value = "answers.toml")]
+    pub answers: PathBuf,
+}
+
+#[derive(Args)]
+pub struct GenerateArgs {
+    #[arg(value_parser = parse_day)]
+    pub day: usize,
+
+    /// The event the day belongs to (the latest year if omitted)
+    #[arg(short, long)]
+    pub year: Option<u16>,
+
+    /// Start from this seed to make the same input again (a random seed if omitted)
+    #[arg(short, long)]
+    pub seed: Option<u64>,
+
+    /// Change the size of the input, e.g. `boxes=5000` (an unknown name lists them all)
+    #[arg(short, long = "knob", value_name = "NAME=VALUE")]
+    pub knobs: Vec<Param>,
+
+    /// Write the input to this file instead of stdout
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct CrosscheckArgs {
+    #[command(flatten)]
+    pub selection: Selection,
+
+    /// How many random inputs to check each day on
+    #[arg(short, long, default_value_t = 100)]
+    pub cases: u64,
+
+    /// The seed of the first input, the others use the seeds after it
+    #[arg(short, long, default_value_t = 0)]
+    pub seed: u64,
+
+    /// Change the size of the inputs of the days that have this knob, e.g. `boxes=50`
+    #[arg(short, long = "knob", value_name = "NAME=VALUE")]
+    pub knobs: Vec<Param>,
+
+    /// Override a solver parameter, e.g. `num_connections=10` (`list` shows them all)
+    #[arg(long = "param", value_name = "NAME=VALUE")]
+    pub params: Vec<Param>,
+}
+
+/// Where to reach Advent of Code and how to log in
+#[derive(Args)]
+pub struct Site {
+    /// The server to talk to, e.g. a local stand-in for testing
+    #[arg(long, env = "AOC_BASE_URL", default_value = "https://adventofcode.com")]
+    pub base_url: String,
+
+    /// File containing the `session` cookie, used when AOC_SESSION isn't set
+    #[arg(long, value_name = "FILE", default_value = ".session")]
+    pub session_file: PathBuf,
+}
+
+/// Which days and parts a command applies to
+#[derive(Args)]
+pub struct Selection {
+    /// The event to run (the latest year if omitted)
+    #[arg(short, long)]
+    pub year: Option<u16>,
+
+    /// Days to run, e.g. `3`, `3..7`, `..5` or `10..` (all days if omitted)
+    pub days: Vec<DayRange>,
+
+    /// Only run one part (1 or 2)
+    #[arg(short, long)]
+    pub part: Option<Part>,
+}
+
+impl Selection {
+    /// The selected days out of those that have a solution, which are in order
+    pub fn days(&self, available: &[usize]) -> Result<Vec<usize>> {
+        resolve_days(&self.days, available)
+    }
+
+    /// Whether the day was given by itself, rather than as part of a range
+    pub fn names(&self, day: usize) -> bool {
+        self.days
+            .iter()
+            .any(|range| range.start == day && range.end == Some(day))
+    }
+}
+
+/// The days in any of the ranges out of those available, or all of them if there are no ranges.
+/// Days in more than one range are only listed once, in order.
+pub fn resolve_days(ranges: &[DayRange], available: &[usize]) -> Result<Vec<usize>> {
+    if ranges.is_empty() {
+        return Ok(available.to_vec());
+    }
+    let mut days: Vec<_> = ranges
+        .iter()
+        .map(|range| range.resolve(available))
+        .flatten_ok()
+        .collect::<Result<_>>()?;
+    days.sort_unstable();
+    days.dedup();
+    Ok(days)
+}
+
+/// A day of an event, which has at most 25
+fn parse_day(day: &str) -> Result<usize> {
+    let day = day
+        .parse()
+        .with_context(|| format!("'{}' is not a valid day number", day))?;
+    ensure!(day >= 1, "days start at 1");
+    ensure!(day <= 25, "days end at 25");
+    Ok(day)
+}
+
+/// An inclusive range of days, where either end may be left open
+#[derive(Clone, Copy)]
+pub struct DayRange {
+    start: usize,
+    end: Option<usize>,
+}
+
+impl DayRange {
+    /// The available days in the range, skipping any gaps
+    fn resolve(&self, available: &[usize]) -> Result<Vec<usize>> {
+        let last_day = available.last().copied().unwrap_or_default();
+        let end = self.end.unwrap_or(last_day);
+        if self.start == end {
+            ensure!(available.contains(&end), "No solution for day {}", end);
+        }
+        let days: Vec<_> = available
+            .iter()
+            .copied()
+            .filter(|day| (self.start..=end).contains(day))
+            .collect();
+        ensure!(
+            !days.is_empty(),
+            "No solutions for days {}..{}",
+            self.start,
+            self.end.map(|end| end.to_string()).unwrap_or_default()
+        );
+        Ok(days)
+    }
+}
+
+impl FromStr for DayRange {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let Some((start, end)) = s.split_once("..") else {
+            let day = parse_day(s)?;
+            return Ok(Self {
+                start: day,
+                end: Some(day),
+            });
+        };
+        // accept Rust's `..=` too, both forms are inclusive
+        let end = end.strip_prefix('=').unwrap_or(end);
+        let start = match start {
+            "" => 1,
+            start => parse_day(start)?,
+        };
+        let end = match end {
+            "" => None,
+            end => Some(parse_day(end)?),
+        };
+        if let Some(end) = end {
+            ensure!(start <= end, "the range {} ends before it starts", s);
+        }
+        Ok(Self { start, end })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::error::ErrorKind;
+
+    fn selection(days: &[&str]) -> Selection {
+        Selection {
+            year: None,
+            days: days.iter().map(|day| day.parse().unwrap()).collect(),
+            part: None,
+        }
+    }
+
+    fn days(range: &str) -> Result<Vec<usize>> {
+        let available: Vec<_> = (1..=12).collect();
+        range.parse::<DayRange>()?.resolve(&available)
+    }
+
+    #[test]
+    fn day_ranges_are_inclusive_and_may_be_open() {
+        assert_eq!(days("3").unwrap(), [3]);
+        assert_eq!(days("3..7").unwrap(), [3, 4, 5, 6, 7]);
+        assert_eq!(days("3..=7").unwrap(), [3, 4, 5, 6, 7]);
+        assert_eq!(days("..5").unwrap(), [1, 2, 3, 4, 5]);
+        assert_eq!(days("10..").unwrap(), [10, 11, 12]);
+    }
+
+    #[test]
+    fn invalid_day_ranges_are_errors() {
+        let error = |range| days(range).unwrap_err().to_string();
+        assert_eq!(error("0"), "days start at 1");
+        assert_eq!(error("0..3"), "days start at 1");
+        assert_eq!(error("7..3"), "the range 7..3 ends before it starts");
+        assert_eq!(error("x"), "'x' is not a valid day number");
+        assert_eq!(error("3..26"), "days end at 25");
+        assert_eq!(error("13"), "No solution for day 13");
+        assert_eq!(error("13.."), "No solutions for days 13..");
+        assert_eq!(error("13..20"), "No solutions for days 13..20");
+    }
+
+    #[test]
+    fn new_days_must_be_in_the_event() {
+        for day in ["0", "26", "99"] {
+            assert!(Cli::try_parse_from(["aoc2025", "new", day]).is_err());
+        }
+        let cli = Cli::try_parse_from(["aoc2025", "new", "13"]).unwrap();
+        let Some(Command::New { day, .. }) = cli.command else {
+            panic!("expected the new command");
+        };
+        assert_eq!(day, 13);
+    }
+
+    #[test]
+    fn overlapping_ranges_list_each_day_once() {
+        let available: Vec<_> = (1..=12).collect();
+        let days = resolve_days(&selection(&["4", "3..5", "1"]).days, &available);
+        assert_eq!(days.unwrap(), [1, 3, 4, 5]);
+    }
+
+    #[test]
+    fn days_of_other_commands_must_be_in_the_event() {
+        let commands: [&[&str]; 3] = [&["submit", "26", "1"], &["watch", "0"], &["generate", "99"]];
+        for args in commands {
+            let err = Cli::try_parse_from(["aoc2025"].iter().chain(args)).err();
+            assert!(err.is_some_and(|err| err.kind() == ErrorKind::ValueValidation));
+        }
+    }
+
+    #[test]
+    fn only_days_given_by_themselves_are_named() {
+        let selection = selection(&["12", "3..7"]);
+        assert!(selection.names(12));
+        assert!(!selection.names(5));
+        assert!(!selection.names(1));
+    }
+}
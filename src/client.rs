@@ -0,0 +1,295 @@
+use crate::cli::Site;
+use anyhow::{Context, Result, bail};
+use aoc2025::solution::Part;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+use std::time::Duration;
+use std::{env, fs};
+use ureq::Agent;
+
+const USER_AGENT: &str = "github.com/AdamSteinberg1/aoc2025";
+
+/// A logged in connection to the Advent of Code site
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(site: &Site) -> Result<Self> {
+        let session = match env::var("AOC_SESSION") {
+            Ok(session) => session,
+            Err(_) => fs::read_to_string(&site.session_file).with_context(|| {
+                format!(
+                    "No session token: set AOC_SESSION or save the `session` cookie \
+                     from adventofcode.com to {}",
+                    site.session_file.display()
+                )
+            })?,
+        };
+        Ok(Self::with_session(&site.base_url, session.trim()))
+    }
+
+    fn with_session(base_url: &str, session: &str) -> Self {
+        // error statuses are explained below rather than reported as bare codes
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .build()
+            .into();
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+
+    /// Downloads the logged in user's input for a day
+    pub fn input(&self, year: u16, day: usize) -> Result<String> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let mut response = self
+            .agent
+            .get(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .call()
+            .with_context(|| format!("Failed to request {}", url))?;
+        let body = response.body_mut().read_to_string()?;
+        check_status(response.status().as_u16(), &url, &body, year, day)?;
+        Ok(body)
+    }
+
+    /// Submits an answer to one part of a day
+    pub fn submit(&self, year: u16, day: usize, part: Part, answer: &str) -> Result<Response> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let mut response = self
+            .agent
+            .post(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .send_form([("level", part.to_string().as_str()), ("answer", answer)])
+            .with_context(|| format!("Failed to post to {}", url))?;
+        let body = response.body_mut().read_to_string()?;
+        check_status(response.status().as_u16(), &url, &body, year, day)?;
+        Response::parse(&body)
+    }
+}
+
+fn check_status(status: u16, url: &str, body: &str, year: u16, day: usize) -> Result<()> {
+    match status {
+        200 => Ok(()),
+        404 => bail!("Day {} of {} hasn't unlocked yet", day, year),
+        400 | 500 => bail!("The session token was rejected, it may have expired"),
+        status => bail!("{} returned {}: {}", url, status, body.trim()),
+    }
+}
+
+/// What the site made of a submitted answer
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The answer wasn't checked because the last one was too recent
+    TooSoon,
+    /// The part is already solved or not unlocked yet
+    WrongLevel,
+}
+
+impl Verdict {
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "wrong, too high",
+            Verdict::TooLow => "wrong, too low",
+            Verdict::Wrong => "wrong",
+            Verdict::TooSoon => "not checked, submitted too soon",
+            Verdict::WrongLevel => "not checked, the part is already solved or still locked",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// The verdict on a submission and how long to wait before the next one
+pub struct Response {
+    pub verdict: Verdict,
+    pub wait: Option<Duration>,
+    /// The site's message, with the markup removed
+    pub message: String,
+}
+
+impl Response {
+    fn parse(html: &str) -> Result<Self> {
+        let message = article_text(html);
+        let verdict = if message.contains("That's the right answer") {
+            Verdict::Correct
+        } else if message.contains("That's not the right answer") {
+            if message.contains("too high") {
+                Verdict::TooHigh
+            } else if message.contains("too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if message.contains("You gave an answer too recently") {
+            Verdict::TooSoon
+        } else if message.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            bail!("Unexpected response to the submission: {}", message);
+        };
+        Ok(Self {
+            verdict,
+            wait: parse_wait(&message),
+            message,
+        })
+    }
+}
+
+/// The text of the page's `<article>`, which holds the response to a submission
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+    let mut text = String::new();
+    let mut in_tag = true; // still inside the opening `<article ...>`
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads either "You have 1m 5s left to wait" or "please wait 5 minutes before trying again"
+fn parse_wait(message: &str) -> Option<Duration> {
+    let message = message.to_lowercase();
+    if let Some((before, _)) = message.split_once(" left to wait") {
+        let (_, left) = before.rsplit_once("you have ")?;
+        let seconds = left.split_whitespace().try_fold(0, |total, amount| {
+            let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+            let number: u64 = number.parse().ok()?;
+            match unit {
+                "h" => Some(total + number * 3600),
+                "m" => Some(total + number * 60),
+                "s" => Some(total + number),
+                _ => None,
+            }
+        })?;
+        return Some(Duration::from_secs(seconds));
+    }
+    let (_, rest) = message.split_once("please wait ")?;
+    let minutes = match rest.split_whitespace().next()? {
+        "one" => 1,
+        number => number.parse().ok()?,
+    };
+    Some(Duration::from_secs(minutes * 60))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// Answers a single request with `status` and `body`, returning the request it received
+    fn serve(status: &'static str, body: &'static str) -> (Client, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}/", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = String::new();
+            let mut reader = BufReader::new(&stream);
+            while reader.read_line(&mut request).unwrap() > 2 {}
+            let length = request
+                .lines()
+                .find_map(|line| line.strip_prefix("content-length: "))
+                .map_or(0, |length| length.parse().unwrap());
+            let mut form = vec![0; length];
+            reader.read_exact(&mut form).unwrap();
+            request.push_str(&String::from_utf8(form).unwrap());
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+            request
+        });
+        (Client::with_session(&base_url, "secret"), server)
+    }
+
+    #[test]
+    fn downloads_input_with_session() {
+        let (client, server) = serve("200 OK", "1 2 3\n");
+        assert_eq!(client.input(2025, 3).unwrap(), "1 2 3\n");
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2025/day/3/input HTTP/1.1"));
+        assert!(request.contains("session=secret"));
+    }
+
+    #[test]
+    fn explains_locked_days() {
+        let (client, server) = serve("404 Not Found", "not yet");
+        let err = client.input(2025, 12).unwrap_err();
+        assert_eq!(err.to_string(), "Day 12 of 2025 hasn't unlocked yet");
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn submits_answer_and_reads_verdict() {
+        let page = "<main><article><p>That's the right answer! You are one gold star closer \
+                    to decorating the North Pole.</p></article></main>";
+        let (client, server) = serve("200 OK", page);
+        let response = client.submit(2025, 3, Part::Two, "357").unwrap();
+        assert_eq!(response.verdict, Verdict::Correct);
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2025/day/3/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=357"));
+    }
+
+    #[test]
+    fn parses_wrong_answers() {
+        let response = Response::parse(
+            "<article><p>That's not the right answer; your answer is too high. \
+             Please wait one minute before trying again.</p></article>",
+        )
+        .unwrap();
+        assert_eq!(response.verdict, Verdict::TooHigh);
+        assert_eq!(response.wait, Some(Duration::from_secs(60)));
+
+        let response = Response::parse(
+            "<article><p>That's not the right answer. Because you have guessed incorrectly \
+             4 times on this puzzle, please wait 5 minutes before trying again.</p></article>",
+        )
+        .unwrap();
+        assert_eq!(response.verdict, Verdict::Wrong);
+        assert_eq!(response.wait, Some(Duration::from_secs(300)));
+    }
+
+    #[test]
+    fn parses_rate_limit() {
+        let response = Response::parse(
+            "<article><p>You gave an answer too recently; you have to wait after submitting \
+             an answer before trying again. You have 1m 5s left to wait.</p></article>",
+        )
+        .unwrap();
+        assert_eq!(response.verdict, Verdict::TooSoon);
+        assert_eq!(response.wait, Some(Duration::from_secs(65)));
+    }
+}
@@ -0,0 +1,99 @@
+//! Checking solutions against their brute-force reference solutions on random inputs.
+
+use crate::registry::Puzzle;
+use crate::solution::{Answer, Param, Part};
+use anyhow::{Context, Result};
+
+/// A part that the solution and its reference answer differently
+#[derive(Debug)]
+pub struct Disagreement {
+    pub part: Part,
+    pub answer: String,
+    pub reference: String,
+}
+
+/// Generates a small input from `seed` and solves it with both the day's solution and its reference,
+/// returning the input and the parts they disagree on.
+///
+/// The input is made with the generator's small knobs, then `knobs`, and solved with the parameters
+/// of the day's first example, then `params`, as the examples are small inputs too.
+pub fn crosscheck(
+    puzzle: &Puzzle,
+    seed: u64,
+    knobs: &[Param],
+    part: Option<Part>,
+    params: &[Param],
+) -> Result<(String, Vec<Disagreement>)> {
+    let generator = puzzle
+        .generator
+        .with_context(|| format!("Day {} has no input generator", puzzle.day))?;
+    let reference = puzzle
+        .reference
+        .with_context(|| format!("Day {} has no reference solution", puzzle.day))?;
+    let knobs = [&generator.small()[..], knobs].concat();
+    let input = generator.generate(seed, &knobs)?;
+
+    let example_params = puzzle
+        .solver
+        .examples()
+        .first()
+        .map(|example| example.params())
+        .unwrap_or_default();
+    let params = [&example_params[..], params].concat();
+    let answers = puzzle.solver.solve(&input, part, &params);
+    let references = reference.solve(&input, part, &params);
+    let disagreements = answers
+        .into_iter()
+        .zip(references)
+        .filter_map(|(answer, reference)| {
+            let part = answer.part;
+            match (answer.answer, reference.answer) {
+                // the references don't explain their answers, so only the values are compared
+                (Ok(answer), Ok(reference)) if answer.value == reference.value => None,
+                // an input that neither can solve says nothing about the solution
+                (Err(_), Err(_)) => None,
+                (answer, reference) => Some(Disagreement {
+                    part,
+                    answer: describe(answer),
+                    reference: describe(reference),
+                }),
+            }
+        })
+        .collect();
+    Ok((input, disagreements))
+}
+
+fn describe(answer: Result<Answer>) -> String {
+    match answer {
+        Ok(answer) => answer.value,
+        Err(err) => format!("error: {:#}", err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+    use proptest::prelude::*;
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn solutions_agree_with_their_references(seed: u64) {
+            for puzzle in registry::puzzles() {
+                if puzzle.generator.is_none() || puzzle.reference.is_none() {
+                    continue;
+                }
+                let (input, disagreements) = crosscheck(puzzle, seed, &[], None, &[]).unwrap();
+                prop_assert!(
+                    disagreements.is_empty(),
+                    "day {} disagrees with its reference on\n{}{:?}",
+                    puzzle.day,
+                    input,
+                    disagreements
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use crate::cli::{self, FetchArgs};
+use crate::client::Client;
+use crate::input;
+use anyhow::{Context, Result};
+use aoc2025::registry;
+use std::fs;
+
+/// The most puzzles an event has had
+const MAX_DAYS: usize = 25;
+
+/// Downloads the selected inputs, skipping any that are already on disk
+pub fn fetch(args: &FetchArgs) -> Result<()> {
+    let year = args
+        .year
+        .or_else(registry::latest_year)
+        .context("Pass --year to choose the event")?;
+    let available = if args.days.is_empty() {
+        registry::days(year)
+    } else {
+        (1..=MAX_DAYS).collect()
+    };
+    // only log in once something actually needs downloading
+    let mut client = None;
+    for day in cli::resolve_days(&args.days, &available)? {
+        let path = input::default_path(year, day);
+        // `new` leaves an empty file to be filled in
+        if fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+            println!("Day {}: already downloaded to {}", day, path.display());
+            continue;
+        }
+        let client = match &mut client {
+            Some(client) => client,
+            None => client.insert(Client::new(&args.site)?),
+        };
+        let input = client.input(year, day)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, input)
+            .with_context(|| format!("Failed to write input file {}", path.display()))?;
+        println!("Day {}: saved to {}", day, path.display());
+    }
+    Ok(())
+}
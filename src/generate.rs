@@ -0,0 +1,135 @@
+//! Random puzzle inputs, for stress-testing and benchmarking the solutions beyond the real input.
+//!
+//! A day's generator is registered next to its solution with [`Puzzle::with_generator`].
+//! Generators only produce inputs that the puzzle description allows, such as a polygon that
+//! never crosses itself, so that any disagreement between solvers points at a solver.
+//!
+//! [`Puzzle::with_generator`]: crate::registry::Puzzle::with_generator
+
+use crate::solution::{Param, parse_param};
+use anyhow::{Context, Result, bail, ensure};
+use rand::rngs::StdRng;
+use rand::{RngExt, SeedableRng};
+use std::fmt::Debug;
+use std::ops::RangeBounds;
+use std::str::FromStr;
+
+pub trait Generator: Default {
+    /// Knobs that make inputs small enough for the brute-force reference solutions
+    const SMALL: &'static [(&'static str, &'static str)];
+
+    /// The sizes this generator can be tuned with, as `(name, value)` pairs
+    fn knobs(&self) -> Vec<(&'static str, String)>;
+
+    /// Overrides one of the knobs listed by `knobs`
+    fn set_knob(&mut self, name: &str, _value: &str) -> Result<()> {
+        bail!("unknown knob '{}'", name)
+    }
+
+    /// A random input in the day's format
+    fn generate(&self, rng: &mut StdRng) -> String;
+}
+
+/// A day's generator with its type erased, so that it can be stored with the day's solver
+#[derive(Clone, Copy)]
+pub struct InputGenerator {
+    generate: fn(u64, &[Param]) -> Result<String>,
+    knobs: fn() -> Vec<(&'static str, String)>,
+    small: &'static [(&'static str, &'static str)],
+}
+
+impl InputGenerator {
+    pub const fn of<T: Generator>() -> Self {
+        Self {
+            generate: generate::<T>,
+            knobs: || T::default().knobs(),
+            small: T::SMALL,
+        }
+    }
+
+    /// Generates an input from `seed`, which is always the same for the same seed and knobs
+    pub fn generate(&self, seed: u64, knobs: &[Param]) -> Result<String> {
+        (self.generate)(seed, knobs)
+    }
+
+    /// The knobs the generator has and their default values
+    pub fn knobs(&self) -> Vec<(&'static str, String)> {
+        (self.knobs)()
+    }
+
+    pub fn has_knob(&self, name: &str) -> bool {
+        self.knobs().iter().any(|&(knob, _)| knob == name)
+    }
+
+    /// The knobs for inputs that the reference solutions can solve quickly
+    pub fn small(&self) -> Vec<Param> {
+        self.small
+            .iter()
+            .map(|&(name, value)| Param {
+                name: name.to_string(),
+                value: value.to_string(),
+            })
+            .collect()
+    }
+}
+
+fn generate<T: Generator>(seed: u64, knobs: &[Param]) -> Result<String> {
+    let mut generator = T::default();
+    for knob in knobs {
+        generator
+            .set_knob(&knob.name, &knob.value)
+            .with_context(|| {
+                let names = generator.knobs().into_iter().map(|(name, _)| name);
+                format!(
+                    "Invalid knob {} (the knobs are {})",
+                    knob,
+                    names.collect::<Vec<_>>().join(", ")
+                )
+            })?;
+    }
+    Ok(generator.generate(&mut StdRng::seed_from_u64(seed)))
+}
+
+/// Parses the value of a knob, which must be within `allowed`
+pub fn parse_knob<T>(name: &str, value: &str, allowed: impl RangeBounds<T> + Debug) -> Result<T>
+where
+    T: FromStr + PartialOrd,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    let value = parse_param(name, value)?;
+    ensure!(
+        allowed.contains(&value),
+        "{} must be in {:?}",
+        name,
+        allowed
+    );
+    Ok(value)
+}
+
+/// A number with exactly `digits` digits
+pub fn number_with_digits(rng: &mut StdRng, digits: u32) -> usize {
+    let low = 10usize.pow(digits - 1);
+    rng.random_range(low..low * 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry;
+    use crate::solution::Part;
+
+    #[test]
+    fn generated_inputs_can_be_solved() {
+        for puzzle in registry::puzzles() {
+            let Some(generator) = puzzle.generator else {
+                continue;
+            };
+            let input = generator.generate(2025, &[]).unwrap();
+            assert_eq!(input, generator.generate(2025, &[]).unwrap());
+            for result in puzzle.solver.solve(&input, Some(Part::One), &[]) {
+                if let Err(err) = result.answer {
+                    panic!("day {} part 1: {:#}", puzzle.day, err);
+                }
+            }
+        }
+    }
+}
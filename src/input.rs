@@ -0,0 +1,125 @@
+use anyhow::{Context, Result, ensure};
+use aoc2025::registry::Puzzle;
+use aoc2025::solution::Param;
+use itertools::Itertools;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// A puzzle input together with a name to report it under
+pub struct Input {
+    pub name: String,
+    pub contents: String,
+    /// Parameters the input needs, applied before any given on the command line
+    pub params: Vec<Param>,
+}
+
+impl Input {
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read input file {}", path.display()))?;
+        Ok(Self {
+            name: path.display().to_string(),
+            contents,
+            params: Vec::new(),
+        })
+    }
+
+    /// Reads a day's input from `inputs/`, explaining how to get it when it isn't there
+    pub fn from_default_path(year: u16, day: usize) -> Result<Self> {
+        let path = default_path(year, day);
+        // inputs used to be kept in `inputs/` without a year
+        let old_path = PathBuf::from(format!("inputs/day{}.txt", day));
+        ensure!(
+            path.exists() || !old_path.exists(),
+            "No input at {}, but there is one at {} from before inputs were kept by year, move it there",
+            path.display(),
+            old_path.display()
+        );
+        let hint = format!("download it with `fetch {} --year {}`", day, year);
+        ensure!(path.exists(), "No input at {}, {}", path.display(), hint);
+        let input = Self::from_file(&path)?;
+        ensure!(
+            !input.contents.is_empty(),
+            "{} is empty, {}",
+            path.display(),
+            hint
+        );
+        Ok(input)
+    }
+
+    fn from_stdin() -> Result<Self> {
+        let mut contents = String::new();
+        io::stdin()
+            .read_to_string(&mut contents)
+            .context("Failed to read input from stdin")?;
+        Ok(Self {
+            name: "stdin".to_string(),
+            contents,
+            params: Vec::new(),
+        })
+    }
+}
+
+pub fn default_path(year: u16, day: usize) -> PathBuf {
+    format!("inputs/{}/day{}.txt", year, day).into()
+}
+
+/// The examples from a day's puzzle description, which are built into its solution
+pub fn examples(puzzle: &Puzzle) -> Result<Vec<Input>> {
+    let examples = puzzle.solver.examples();
+    ensure!(!examples.is_empty(), "Day {} has no examples", puzzle.day);
+    let inputs = examples
+        .iter()
+        .enumerate()
+        .map(|(i, example)| Input {
+            name: match examples.len() {
+                1 => "example".to_string(),
+                _ => format!("example {}", i + 1),
+            },
+            contents: example.input.to_string(),
+            params: example.params(),
+        })
+        .collect();
+    Ok(inputs)
+}
+
+/// Reads every input named on the command line, where `-` means stdin
+/// and a directory stands for all of the files it contains
+pub fn read_inputs(paths: &[PathBuf]) -> Result<Vec<Input>> {
+    ensure!(
+        paths.iter().filter(|path| is_stdin(path)).count() <= 1,
+        "stdin can only be read once"
+    );
+    let inputs: Vec<_> = paths
+        .iter()
+        .map(|path| -> Result<Vec<Input>> {
+            if is_stdin(path) {
+                Ok(vec![Input::from_stdin()?])
+            } else if path.is_dir() {
+                directory_files(path)?
+                    .iter()
+                    .map(|file| Input::from_file(file))
+                    .collect()
+            } else {
+                Ok(vec![Input::from_file(path)?])
+            }
+        })
+        .flatten_ok()
+        .try_collect()?;
+    ensure!(!inputs.is_empty(), "no input files found");
+    Ok(inputs)
+}
+
+fn is_stdin(path: &Path) -> bool {
+    path.as_os_str() == "-"
+}
+
+fn directory_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let files = fs::read_dir(dir)
+        .with_context(|| format!("Failed to read input directory {}", dir.display()))?
+        .map_ok(|entry| entry.path())
+        .filter_ok(|path| path.is_file())
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(files.into_iter().sorted().collect())
+}
@@ -0,0 +1,15 @@
+//! Solutions to Advent of Code.
+//!
+//! Each event has a `yYYYY` module with one `dayN` module per puzzle. Every day implements
+//! [`solution::Solution`], exposes the parsing and solving functions it is built from,
+//! and registers itself in [`registry`], along with a [`generate::Generator`] of random inputs
+//! and a brute-force reference solution that [`crosscheck`] compares it with.
+
+pub mod crosscheck;
+pub mod generate;
+pub mod memory;
+pub mod panics;
+pub mod parse;
+pub mod registry;
+pub mod solution;
+pub mod y2025;
@@ -0,0 +1,426 @@
+use crate::report::format_size;
+use anyhow::{Context, Result, anyhow, bail, ensure};
+use aoc2025::memory::AllocStats;
+use aoc2025::registry::{self, PartResult, Puzzle};
+use aoc2025::solution::{Answer, Param, Part};
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+use std::{env, fs, thread};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(2);
+
+/// Limits on the resources a single part may use.
+/// Parts are run in a child process whenever a limit is set so that they can be stopped.
+/// The input is parsed once for all the parts, and the first part's limits cover the parsing.
+#[derive(Args, Clone, Copy)]
+pub struct Limits {
+    /// Stop a part after this much wall-clock time, e.g. `30s` or `500ms`
+    #[arg(long, value_parser = parse_duration)]
+    pub timeout: Option<Duration>,
+
+    /// Stop a part once the process solving it would allocate more memory than this,
+    /// e.g. `512M` or `2G` (Linux only)
+    #[arg(long, value_parser = parse_size)]
+    pub max_memory: Option<u64>,
+}
+
+impl Limits {
+    pub fn is_set(&self) -> bool {
+        self.timeout.is_some() || self.max_memory.is_some()
+    }
+}
+
+/// What a worker process reports back for each part, on its own line as soon as the part is solved
+#[derive(Serialize, Deserialize)]
+struct WorkerOutput {
+    answer: Result<Answer, String>,
+    parse_ns: u64,
+    solve_ns: u64,
+    memory: Option<AllocStats>,
+}
+
+/// What a worker process reported before it exited or was stopped
+struct Progress {
+    outputs: Vec<WorkerOutput>,
+    /// When the worker started on the part it hasn't reported yet
+    part_start: Instant,
+}
+
+/// Parses the input once and solves the selected parts from it in a child process,
+/// stopping it if a part exceeds the limits.
+/// The parts reported before it was stopped keep their results, and only the rest fail.
+pub fn run_parts(
+    puzzle: &Puzzle,
+    part: Option<Part>,
+    input: &str,
+    params: &[Param],
+    limits: &Limits,
+) -> Vec<PartResult> {
+    let parts: Vec<_> = puzzle
+        .solver
+        .parts()
+        .filter(|p| p.is_selected(part))
+        .collect();
+    let mut progress = Progress {
+        outputs: Vec::new(),
+        part_start: Instant::now(),
+    };
+    let stopped = run_worker(
+        puzzle,
+        part,
+        input,
+        params,
+        limits,
+        parts.len(),
+        &mut progress,
+    );
+    let stopped_elapsed = progress.part_start.elapsed();
+    let reported = progress.outputs.len();
+    let mut outputs = progress.outputs.into_iter();
+    parts
+        .into_iter()
+        .enumerate()
+        .map(|(i, part)| match outputs.next() {
+            Some(output) => PartResult {
+                part,
+                answer: output.answer.map_err(|err| anyhow!(err)),
+                parse_elapsed: Duration::from_nanos(output.parse_ns),
+                elapsed: Duration::from_nanos(output.solve_ns),
+                memory: output.memory,
+            },
+            None => PartResult {
+                part,
+                answer: Err(match &stopped {
+                    Err(err) => anyhow!("{:#}", err),
+                    Ok(()) => anyhow!("worker process did not report part {}", part),
+                }),
+                parse_elapsed: Duration::ZERO,
+                // the parts after the one that was stopped never started
+                elapsed: if i == reported {
+                    stopped_elapsed
+                } else {
+                    Duration::ZERO
+                },
+                memory: None,
+            },
+        })
+        .collect()
+}
+
+/// Runs a worker process, collecting the parts it reports into `progress`.
+/// The deadline and the reported peak memory start over with each part.
+fn run_worker(
+    puzzle: &Puzzle,
+    part: Option<Part>,
+    input: &str,
+    params: &[Param],
+    limits: &Limits,
+    parts: usize,
+    progress: &mut Progress,
+) -> Result<()> {
+    ensure!(
+        limits.max_memory.is_none() || cfg!(target_os = "linux"),
+        "--max-memory is only supported on Linux"
+    );
+    let mut child = Command::new(env::current_exe()?)
+        .arg("worker")
+        .args([puzzle.year.to_string(), puzzle.day.to_string()])
+        .args(part.map(|part| format!("--part={}", part)))
+        .args(params.iter().map(|param| format!("--param={}", param)))
+        .args(
+            limits
+                .max_memory
+                .map(|bytes| format!("--max-memory={}", bytes)),
+        )
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Failed to start worker process")?;
+
+    // the first part's deadline covers handing over the input too
+    progress.part_start = Instant::now();
+    let mut stdin = child.stdin.take().context("worker has no stdin")?;
+    if let Err(err) = stdin.write_all(input.as_bytes()) {
+        kill(&mut child);
+        return Err(err).context("Failed to send the input to the worker process");
+    }
+    drop(stdin);
+    let lines = read_lines_in_background(child.stdout.take().context("worker has no stdout")?);
+    let stderr = read_in_background(child.stderr.take().context("worker has no stderr")?);
+
+    let mut peak = None;
+    let status = loop {
+        while let Ok(line) = lines.try_recv() {
+            progress.outputs.push(read_output(&line)?);
+            progress.part_start = Instant::now();
+            peak = None;
+        }
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if let Some(timeout) = limits.timeout
+            && progress.part_start.elapsed() > timeout
+        {
+            kill(&mut child);
+            bail!("timed out after {:.2?}", timeout);
+        }
+        if limits.max_memory.is_some() {
+            peak = peak_memory(&child).or(peak);
+        }
+        thread::sleep(POLL_INTERVAL);
+    };
+
+    // the reader only stops once the worker has exited, so this picks up any lines it wrote last
+    for line in lines {
+        progress.outputs.push(read_output(&line)?);
+    }
+    if !status.success() {
+        let stderr = stderr.join().unwrap_or_default();
+        // the allocation that would go over the cap fails, which aborts the worker
+        if let Some(max_memory) = limits.max_memory
+            && stderr.contains("memory allocation of")
+        {
+            let peak = peak
+                .map(|peak| format!(" (peak {})", format_size(peak)))
+                .unwrap_or_default();
+            bail!(
+                "exceeded memory limit of {}{}",
+                format_size(max_memory),
+                peak
+            );
+        }
+        bail!("worker process failed ({}): {}", status, stderr.trim());
+    }
+    ensure!(
+        progress.outputs.len() == parts,
+        "worker process reported {} of {} parts",
+        progress.outputs.len(),
+        parts
+    );
+    Ok(())
+}
+
+fn read_output(line: &str) -> Result<WorkerOutput> {
+    serde_json::from_str(line).context("Failed to read the worker's result")
+}
+
+/// Sends each line of the pipe over the channel as soon as it is written
+fn read_lines_in_background(pipe: impl Read + Send + 'static) -> mpsc::Receiver<String> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for line in BufReader::new(pipe).lines().map_while(Result::ok) {
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    });
+    receiver
+}
+
+fn read_in_background(mut pipe: impl Read + Send + 'static) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut contents = String::new();
+        let _ = pipe.read_to_string(&mut contents);
+        contents
+    })
+}
+
+fn kill(child: &mut Child) {
+    // the child may have exited in the meantime, which is fine
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+/// The most memory a process has had resident in bytes, since it started
+/// or since it last reset the mark
+fn peak_memory(child: &Child) -> Option<u64> {
+    let status = fs::read_to_string(format!("/proc/{}/status", child.id())).ok()?;
+    let kilobytes = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmHWM:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+    Some(kilobytes * 1024)
+}
+
+/// The entry point of a worker process: solves the selected parts of the input on stdin
+/// and prints each part's result as a line of JSON as soon as the part is solved
+pub fn worker(
+    year: u16,
+    day: usize,
+    part: Option<Part>,
+    params: &[Param],
+    max_memory: Option<u64>,
+) -> Result<()> {
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input)?;
+    let puzzle = registry::puzzle(year, day)?;
+    if let Some(bytes) = max_memory {
+        cap_memory(bytes)?;
+    }
+    let mut stdout = io::stdout().lock();
+    let mut written = Ok(());
+    puzzle
+        .solver
+        .solve_each(&input, part, params, &mut |result| {
+            if written.is_ok() {
+                written = write_output(&mut stdout, result);
+            }
+        });
+    written
+}
+
+fn write_output(out: &mut impl Write, result: PartResult) -> Result<()> {
+    let output = WorkerOutput {
+        answer: result.answer.map_err(|err| format!("{:#}", err)),
+        parse_ns: result.parse_elapsed.as_nanos() as u64,
+        solve_ns: result.elapsed.as_nanos() as u64,
+        memory: result.memory,
+    };
+    // start the next part's peak from what is in use now, before the parent sees this part
+    reset_peak_memory();
+    writeln!(out, "{}", serde_json::to_string(&output)?)?;
+    // the parent restarts the limits for the next part once it sees this line
+    out.flush()?;
+    Ok(())
+}
+
+/// Caps the memory this process may allocate, so that the allocation that would go over it fails
+/// rather than the process growing past it between two checks of its memory use
+#[cfg(target_os = "linux")]
+fn cap_memory(bytes: u64) -> Result<()> {
+    let limit = libc::rlimit {
+        rlim_cur: bytes,
+        rlim_max: bytes,
+    };
+    let result = unsafe { libc::setrlimit(libc::RLIMIT_DATA, &limit) };
+    ensure!(
+        result == 0,
+        "Failed to cap memory at {}: {}",
+        format_size(bytes),
+        io::Error::last_os_error()
+    );
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn cap_memory(_bytes: u64) -> Result<()> {
+    bail!("--max-memory is only supported on Linux")
+}
+
+/// Resets this process's peak memory to what it has resident now
+fn reset_peak_memory() {
+    // the peak is only reported, so a kernel that can't reset it just reports a higher one
+    let _ = fs::write("/proc/self/clear_refs", "5");
+}
+
+fn parse_duration(s: &str) -> Result<Duration> {
+    let split = s.find(|c: char| c.is_alphabetic()).unwrap_or(s.len());
+    let (amount, unit) = s.split_at(split);
+    let amount: f64 = amount
+        .parse()
+        .with_context(|| format!("invalid duration '{}'", s))?;
+    let seconds = match unit {
+        "ms" => amount / 1000.0,
+        "s" | "" => amount,
+        "m" => amount * 60.0,
+        _ => bail!("unknown unit '{}', expected ms, s or m", unit),
+    };
+    ensure!(seconds > 0.0, "duration must be positive, got '{}'", s);
+    let duration = Duration::try_from_secs_f64(seconds)
+        .with_context(|| format!("duration '{}' is too long", s))?;
+    ensure!(!duration.is_zero(), "duration '{}' is too short", s);
+    Ok(duration)
+}
+
+fn parse_size(s: &str) -> Result<u64> {
+    let split = s.find(|c: char| c.is_alphabetic()).unwrap_or(s.len());
+    let (amount, unit) = s.split_at(split);
+    let amount: f64 = amount
+        .parse()
+        .with_context(|| format!("invalid size '{}'", s))?;
+    let unit = unit.trim_end_matches("iB").trim_end_matches('B');
+    let factor = match unit.to_ascii_uppercase().as_str() {
+        "" => 1,
+        "K" => 1 << 10,
+        "M" => 1 << 20,
+        "G" => 1 << 30,
+        _ => bail!("unknown unit in '{}', expected K, M or G", s),
+    };
+    let bytes = amount * factor as f64;
+    ensure!(bytes >= 1.0, "size must be positive, got '{}'", s);
+    // u64::MAX rounds up to 2^64 as a float, so anything from there on overflows
+    ensure!(bytes < u64::MAX as f64, "size '{}' is too large", s);
+    Ok(bytes as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn durations_have_units() {
+        assert_eq!(parse_duration("500ms").unwrap(), Duration::from_millis(500));
+        assert_eq!(parse_duration("1.5s").unwrap(), Duration::from_millis(1500));
+        assert_eq!(parse_duration("30").unwrap(), Duration::from_secs(30));
+        assert_eq!(parse_duration("2m").unwrap(), Duration::from_secs(120));
+        let err = parse_duration("5h").unwrap_err();
+        assert_eq!(err.to_string(), "unknown unit 'h', expected ms, s or m");
+    }
+
+    #[test]
+    fn durations_must_be_positive_and_in_range() {
+        for s in ["-1s", "0s", "0", "-0.5m"] {
+            let err = parse_duration(s).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("duration must be positive, got '{}'", s)
+            );
+        }
+        let err = parse_duration("99999999999999999999m").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "duration '99999999999999999999m' is too long"
+        );
+        // so many digits that the amount is infinite
+        assert!(parse_duration(&format!("1{}s", "0".repeat(400))).is_err());
+        assert!(parse_duration("0.000000000001ms").is_err());
+        let err = parse_duration("NaN").unwrap_err();
+        assert_eq!(err.to_string(), "invalid duration 'NaN'");
+    }
+
+    #[test]
+    fn sizes_have_units() {
+        assert_eq!(parse_size("512").unwrap(), 512);
+        assert_eq!(parse_size("4K").unwrap(), 4 << 10);
+        assert_eq!(parse_size("512MB").unwrap(), 512 << 20);
+        assert_eq!(parse_size("2GiB").unwrap(), 2 << 30);
+        assert_eq!(parse_size("1.5g").unwrap(), 3 << 29);
+        let err = parse_size("2T").unwrap_err();
+        assert_eq!(err.to_string(), "unknown unit in '2T', expected K, M or G");
+    }
+
+    #[test]
+    fn sizes_must_be_positive_and_in_range() {
+        for s in ["-5G", "0", "0.5", "-1K"] {
+            let err = parse_size(s).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("size must be positive, got '{}'", s)
+            );
+        }
+        let err = parse_size("99999999999G").unwrap_err();
+        assert_eq!(err.to_string(), "size '99999999999G' is too large");
+        assert!(parse_size(&format!("1{}", "0".repeat(400))).is_err());
+        let err = parse_size("inf").unwrap_err();
+        assert_eq!(err.to_string(), "invalid size 'inf'");
+    }
+}
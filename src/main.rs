@@ -1,85 +1,405 @@
-mod day1;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-mod day10;
-mod day11;
-mod day12;
-mod solution;
+mod answers;
+mod bench;
+mod cli;
+mod client;
+mod fetch;
+mod input;
+mod limits;
+mod report;
+mod scaffold;
+mod submit;
+mod table;
+mod verify;
+mod watch;
 
-use crate::solution::Solution;
-use anyhow::{Context, Result};
-use std::{env, fmt, fs, time};
+use crate::cli::{Cli, Command, CrosscheckArgs, GenerateArgs, RunArgs, Selection};
+use crate::input::Input;
+use crate::limits::Limits;
+use crate::report::{Format, Record};
+use crate::table::Table;
+use anyhow::{Context, Result, anyhow, bail, ensure};
+use aoc2025::registry::{self, PartResult, Puzzle};
+use aoc2025::solution::{Param, Part};
+use aoc2025::{crosscheck, panics};
+use clap::Parser;
+use itertools::Itertools;
+use rayon::ThreadPoolBuilder;
+use rayon::prelude::*;
+use std::fs;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
 
-const SOLVERS: &[fn(&str) -> Result<()>] = &[
-    solve::<day1::Day1>,
-    solve::<day2::Day2>,
-    solve::<day3::Day3>,
-    solve::<day4::Day4>,
-    solve::<day5::Day5>,
-    solve::<day6::Day6>,
-    solve::<day7::Day7>,
-    solve::<day8::Day8>,
-    solve::<day9::Day9>,
-    solve::<day10::Day10>,
-    solve::<day11::Day11>,
-    solve::<day12::Day12>,
-];
+fn main() -> Result<ExitCode> {
+    let cli = Cli::parse();
+    panics::install_hook();
+    match cli.command.unwrap_or(Command::Run(cli.run)) {
+        Command::Run(args) => {
+            if !run(&args)? {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Command::Bench(args) => {
+            if !bench::bench(&args)? {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Command::Verify(args) => {
+            if !verify::verify(&args)? {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Command::List { year } => list(year),
+        Command::Fetch(args) => fetch::fetch(&args)?,
+        Command::Submit(args) => {
+            if !submit::submit(&args)? {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Command::Watch(args) => watch::watch(&args)?,
+        Command::Generate(args) => generate(&args)?,
+        Command::Crosscheck(args) => {
+            if !crosscheck(&args)? {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Command::New { day, year, title } => {
+            // unlike the other commands, `new` may start a year that has no solutions yet
+            let year = year
+                .or_else(registry::latest_year)
+                .context("Pass --year to add the first day")?;
+            scaffold::new_day(year, day, &title)?
+        }
+        Command::Worker {
+            year,
+            day,
+            part,
+            params,
+            max_memory,
+        } => limits::worker(year, day, part, &params, max_memory)?,
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// The chosen year, or the latest year with solutions
+fn resolve_year(year: Option<u16>) -> Result<u16> {
+    match year {
+        Some(year) => {
+            ensure!(
+                !registry::days(year).is_empty(),
+                "No solutions for {}",
+                year
+            );
+            Ok(year)
+        }
+        None => registry::latest_year().context("No solutions have been registered"),
+    }
+}
+
+/// The puzzles for the selected days, leaving out those in a range without the selected part.
+/// A day given by itself must have the part.
+fn select(selection: &Selection) -> Result<Vec<&'static Puzzle>> {
+    let year = resolve_year(selection.year)?;
+    let mut puzzles: Vec<_> = selection
+        .days(&registry::days(year))?
+        .into_iter()
+        .map(|day| registry::puzzle(year, day))
+        .try_collect()?;
+    if let Some(part) = selection.part {
+        let has_part = |puzzle: &Puzzle| puzzle.solver.parts().any(|p| p == part);
+        if let Some(puzzle) = puzzles
+            .iter()
+            .find(|puzzle| selection.names(puzzle.day) && !has_part(puzzle))
+        {
+            bail!("Day {} has no part {}", puzzle.day, part);
+        }
+        puzzles.retain(|puzzle| has_part(puzzle));
+        ensure!(
+            !puzzles.is_empty(),
+            "None of the selected days has a part {}",
+            part
+        );
+    }
+    Ok(puzzles)
+}
 
-fn main() -> Result<()> {
-    let day_num = env::args().nth(1);
-    if let Some(day_num) = day_num {
-        let day_num: usize = day_num
-            .parse()
-            .context("Day number must be a valid integer")?;
-        run_day(day_num)?;
+/// Parses an input once and solves the selected parts from it,
+/// in a separate process if it needs to be kept within limits
+fn solve_input(
+    puzzle: &Puzzle,
+    part: Option<Part>,
+    input: &str,
+    params: &[Param],
+    limits: &Limits,
+) -> Vec<PartResult> {
+    if limits.is_set() {
+        limits::run_parts(puzzle, part, input, params, limits)
     } else {
-        run_all_days()?;
+        puzzle.solver.solve(input, part, params)
     }
-    Ok(())
 }
 
-fn solve<T: Solution>(input: &str) -> Result<()> {
-    let solution = T::default();
-    print!("Part 1: ");
-    print_with_runtime(|| solution.part1(input))?;
-    print!("Part 2: ");
-    print_with_runtime(|| solution.part2(input))?;
+/// Rejects parameters that none of the puzzles have, as they are most likely typos
+fn check_params(puzzles: &[&Puzzle], params: &[Param]) -> Result<()> {
+    for param in params {
+        if !puzzles
+            .iter()
+            .any(|puzzle| puzzle.solver.has_param(&param.name))
+        {
+            bail!(
+                "None of the selected days has a parameter named '{}'",
+                param.name
+            );
+        }
+    }
     Ok(())
 }
 
-fn print_with_runtime<F, T>(part: F) -> Result<()>
-where
-    F: FnOnce() -> Result<T>,
-    T: fmt::Display,
-{
-    let now = time::Instant::now();
-    let result = part()?;
-    let elapsed = now.elapsed();
-    println!("{}\t{:.2?}", result, elapsed);
-    Ok(())
+/// Runs the selected days, returning whether every part succeeded
+fn run(args: &RunArgs) -> Result<bool> {
+    let puzzles = select(&args.selection)?;
+    check_params(&puzzles, &args.params)?;
+    let days: Vec<(&Puzzle, Result<Vec<Input>>)> = if args.example {
+        puzzles
+            .into_iter()
+            .map(|puzzle| (puzzle, input::examples(puzzle)))
+            .collect()
+    } else if args.inputs.is_empty() {
+        puzzles
+            .into_iter()
+            .map(|puzzle| {
+                let input = Input::from_default_path(puzzle.year, puzzle.day);
+                (puzzle, input.map(|input| vec![input]))
+            })
+            .collect()
+    } else {
+        let [puzzle] = puzzles[..] else {
+            bail!("--input can only be used with a single day");
+        };
+        vec![(puzzle, input::read_inputs(&args.inputs))]
+    };
+
+    if cfg!(feature = "count-allocations") && args.jobs != 1 {
+        eprintln!(
+            "Allocations are counted for every thread at once, so run with --jobs 1 to tell parts apart"
+        );
+    }
+    let pool = ThreadPoolBuilder::new().num_threads(args.jobs).build()?;
+    let start = Instant::now();
+    let mut records = Vec::new();
+    let mut report_day = |(puzzle, inputs, results): SolvedDay| {
+        if args.format == Format::Text {
+            report::print_day(puzzle.day, &inputs, &results);
+        }
+        for (input, results) in inputs.iter().zip(&results) {
+            records.extend(
+                results
+                    .iter()
+                    .map(|result| Record::new(puzzle, input, result)),
+            );
+        }
+    };
+    if args.jobs == 1 {
+        // print each day as soon as it is solved
+        for (puzzle, inputs) in days {
+            report_day(pool.install(|| solve_day(puzzle, inputs, args)));
+        }
+    } else {
+        let solved: Vec<_> = pool.install(|| {
+            days.into_par_iter()
+                .map(|(puzzle, inputs)| solve_day(puzzle, inputs, args))
+                .collect()
+        });
+        solved.into_iter().for_each(report_day);
+    }
+    let wall_time = start.elapsed();
+
+    if args.format == Format::Text {
+        report::print_summary(&records, wall_time);
+    } else {
+        report::write(args.format, &records)?;
+    }
+    Ok(records.iter().all(|record| record.error.is_none()))
 }
 
-fn run_day(num: usize) -> Result<()> {
-    let solve = SOLVERS
-        .get(num - 1)
-        .with_context(|| format!("No solution for day {}", num))?;
-    let path = format!("inputs/day{}.txt", num);
-    let input = fs::read_to_string(path)?;
-    println!("--- Day {} ---", num);
-    solve(&input)?;
-    println!();
-    Ok(())
+type SolvedDay = (&'static Puzzle, Vec<Input>, Vec<Vec<PartResult>>);
+
+/// Solves every input of a day, in parallel when run on a larger thread pool
+fn solve_day(puzzle: &'static Puzzle, inputs: Result<Vec<Input>>, args: &RunArgs) -> SolvedDay {
+    let parts: Vec<_> = puzzle
+        .solver
+        .parts()
+        .filter(|part| part.is_selected(args.selection.part))
+        .collect();
+    match inputs {
+        Ok(inputs) => {
+            let results = inputs
+                .par_iter()
+                .map(|input| {
+                    let params = [&input.params[..], &args.params[..]].concat();
+                    solve_input(
+                        puzzle,
+                        args.selection.part,
+                        &input.contents,
+                        &params,
+                        &args.limits,
+                    )
+                })
+                .collect();
+            (puzzle, inputs, results)
+        }
+        Err(err) => {
+            // report the missing input against every part that would have run
+            let name = if args.example {
+                "example".to_string()
+            } else if args.inputs.is_empty() {
+                input::default_path(puzzle.year, puzzle.day)
+                    .display()
+                    .to_string()
+            } else {
+                args.inputs.iter().map(|path| path.display()).join(", ")
+            };
+            let input = Input {
+                name,
+                contents: String::new(),
+                params: Vec::new(),
+            };
+            let results = parts
+                .into_iter()
+                .map(|part| PartResult {
+                    part,
+                    answer: Err(anyhow!("{:#}", err)),
+                    parse_elapsed: Duration::ZERO,
+                    elapsed: Duration::ZERO,
+                    memory: None,
+                })
+                .collect();
+            (puzzle, vec![input], vec![results])
+        }
+    }
 }
 
-fn run_all_days() -> Result<()> {
-    for day in 1..=SOLVERS.len() {
-        run_day(day)?;
+/// Writes a random input for a day, reporting the seed so that it can be made again
+fn generate(args: &GenerateArgs) -> Result<()> {
+    let year = resolve_year(args.year)?;
+    let puzzle = registry::puzzle(year, args.day)?;
+    let generator = puzzle
+        .generator
+        .with_context(|| format!("Day {} has no input generator", args.day))?;
+    let seed = args.seed.unwrap_or_else(rand::random);
+    let input = generator.generate(seed, &args.knobs)?;
+    match &args.output {
+        Some(path) => {
+            fs::write(path, input)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            println!("Wrote {} with seed {}", path.display(), seed);
+        }
+        None => {
+            eprintln!("Seed {}", seed);
+            print!("{}", input);
+        }
     }
     Ok(())
 }
+
+/// Checks each selected day against its reference solution, stopping a day at its first
+/// disagreement, and returns whether they all agreed
+fn crosscheck(args: &CrosscheckArgs) -> Result<bool> {
+    let puzzles: Vec<_> = select(&args.selection)?
+        .into_iter()
+        .filter(|puzzle| puzzle.generator.is_some() && puzzle.reference.is_some())
+        .collect();
+    ensure!(
+        !puzzles.is_empty(),
+        "None of the selected days has both an input generator and a reference solution"
+    );
+    check_params(&puzzles, &args.params)?;
+    for knob in &args.knobs {
+        ensure!(
+            puzzles
+                .iter()
+                .filter_map(|puzzle| puzzle.generator)
+                .any(|generator| generator.has_knob(&knob.name)),
+            "None of the selected days has a knob named '{}'",
+            knob.name
+        );
+    }
+
+    let mut agreed = true;
+    for puzzle in puzzles {
+        let Some(generator) = puzzle.generator else {
+            continue;
+        };
+        let knobs: Vec<_> = args
+            .knobs
+            .iter()
+            .filter(|knob| generator.has_knob(&knob.name))
+            .cloned()
+            .collect();
+        let params: Vec<_> = args
+            .params
+            .iter()
+            .filter(|param| puzzle.solver.has_param(&param.name))
+            .cloned()
+            .collect();
+        let mut disagreement = None;
+        for seed in args.seed..args.seed.saturating_add(args.cases) {
+            let (_, disagreements) =
+                crosscheck::crosscheck(puzzle, seed, &knobs, args.selection.part, &params)?;
+            if let Some(first) = disagreements.into_iter().next() {
+                disagreement = Some((seed, first));
+                break;
+            }
+        }
+        match disagreement {
+            None => println!("Day {}: {} inputs agree", puzzle.day, args.cases),
+            Some((seed, disagreement)) => {
+                agreed = false;
+                println!(
+                    "Day {} part {} with seed {}: answer {}, reference {}",
+                    puzzle.day,
+                    disagreement.part,
+                    seed,
+                    disagreement.answer,
+                    disagreement.reference
+                );
+                let small = generator.small();
+                let knobs = small
+                    .iter()
+                    .chain(&knobs)
+                    .map(|knob| format!(" --knob {}", knob))
+                    .join("");
+                println!(
+                    "  reproduce with: generate {} --year {} --seed {}{}",
+                    puzzle.day, puzzle.year, seed, knobs
+                );
+            }
+        }
+    }
+    Ok(agreed)
+}
+
+fn list(year: Option<u16>) {
+    let mut table = Table::new(["Year", "Day", "Title", "Parts", "Input", "Parameters"]);
+    for puzzle in registry::puzzles() {
+        if year.is_some_and(|year| year != puzzle.year) {
+            continue;
+        }
+        let path = input::default_path(puzzle.year, puzzle.day);
+        let status = if path.exists() { "" } else { " (missing)" };
+        let params = puzzle
+            .solver
+            .params()
+            .into_iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .join(" ");
+        table.push([
+            puzzle.year.to_string(),
+            puzzle.day.to_string(),
+            puzzle.title.to_string(),
+            puzzle.solver.parts().count().to_string(),
+            format!("{}{}", path.display(), status),
+            params,
+        ]);
+    }
+    print!("{}", table);
+}
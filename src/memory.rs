@@ -0,0 +1,132 @@
+//! Counting the heap allocations made while solving.
+//!
+//! With the `count-allocations` feature, the global allocator counts every allocation,
+//! so that the runner can report how much each part allocates next to how long it takes.
+//! The counters are shared by every thread, so the numbers are only meaningful
+//! while one part runs at a time.
+
+use serde::{Deserialize, Serialize};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+static CURRENT: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// The system allocator, counting what passes through it
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn record_alloc(size: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED.fetch_add(size as u64, Ordering::Relaxed);
+        let current = CURRENT.fetch_add(size as u64, Ordering::Relaxed) + size as u64;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn record_dealloc(size: usize) {
+        CURRENT.fetch_sub(size as u64, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        Self::record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            // a reallocation counts as freeing the old block and allocating the new one
+            Self::record_dealloc(layout.size());
+            Self::record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// What was allocated while measuring
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllocStats {
+    pub allocations: u64,
+    /// Every byte allocated, including those freed again
+    pub bytes: u64,
+    /// The most heap in use at once, above what was in use when measuring started
+    pub peak: u64,
+}
+
+/// The counters when a measurement started
+pub struct Measurement {
+    allocations: u64,
+    allocated: u64,
+    baseline: u64,
+}
+
+impl Measurement {
+    /// Starts measuring, or returns `None` when allocations aren't being counted
+    pub fn start() -> Option<Self> {
+        if !cfg!(feature = "count-allocations") {
+            return None;
+        }
+        let baseline = CURRENT.load(Ordering::Relaxed);
+        PEAK.store(baseline, Ordering::Relaxed);
+        Some(Self {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+            allocated: ALLOCATED.load(Ordering::Relaxed),
+            baseline,
+        })
+    }
+
+    /// Starts measuring a step that follows this one, such as solving a part after parsing,
+    /// keeping the peak relative to the heap in use before either
+    pub fn resume(&self) -> Self {
+        PEAK.store(CURRENT.load(Ordering::Relaxed), Ordering::Relaxed);
+        Self {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+            allocated: ALLOCATED.load(Ordering::Relaxed),
+            baseline: self.baseline,
+        }
+    }
+
+    /// What was allocated since the measurement started
+    pub fn stop(&self) -> AllocStats {
+        AllocStats {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed) - self.allocations,
+            bytes: ALLOCATED.load(Ordering::Relaxed) - self.allocated,
+            peak: PEAK.load(Ordering::Relaxed).saturating_sub(self.baseline),
+        }
+    }
+}
+
+impl AllocStats {
+    /// The allocations of two steps that ran one after the other
+    pub fn then(self, next: AllocStats) -> AllocStats {
+        AllocStats {
+            allocations: self.allocations + next.allocations,
+            bytes: self.bytes + next.bytes,
+            peak: self.peak.max(next.peak),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use anyhow::{Result, anyhow};
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static MESSAGE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Replaces the panic hook so that panics inside `catch` are recorded
+/// instead of printed, leaving every other panic to the default hook
+pub fn install_hook() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if !CATCHING.get() {
+            return default_hook(info);
+        }
+        let payload = info.payload_as_str().unwrap_or("Box<dyn Any>");
+        let message = match info.location() {
+            Some(location) => format!("panicked at {}: {}", location, payload),
+            None => format!("panicked: {}", payload),
+        };
+        MESSAGE.set(Some(message));
+    }));
+}
+
+/// Runs `f`, turning a panic into an error
+pub fn catch<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+    let was_catching = CATCHING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(was_catching);
+    result.unwrap_or_else(|_| {
+        let message = MESSAGE.take().unwrap_or_else(|| "panicked".to_string());
+        Err(anyhow!(message))
+    })
+}
@@ -0,0 +1,364 @@
+//! Parsing the shapes that puzzle inputs share, with errors that point at where the input went wrong.
+
+use itertools::Itertools;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::iter;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// A problem with the puzzle input, with the line, column and text it was found at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+    /// Byte offset of the offending text in the input
+    offset: usize,
+    /// Length of the offending text in bytes
+    len: usize,
+    /// Length of the offending text in characters, cut off at the end of its line
+    width: usize,
+    /// 1-based line number
+    line: usize,
+    /// 1-based column, counted in characters
+    column: usize,
+    /// The whole line containing the offending text
+    snippet: String,
+}
+
+impl ParseError {
+    /// An error at `span`, which must be a slice of `input`
+    pub fn new(input: &str, span: &str, message: impl Display) -> Self {
+        let offset = offset_in(input, span);
+        Self::at(input, offset, span.len(), message.to_string())
+    }
+
+    fn at(input: &str, offset: usize, len: usize, message: String) -> Self {
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        let snippet = input[line_start..line_end].trim_end_matches('\r');
+        let end = (offset + len).min(line_start + snippet.len()).max(offset);
+        Self {
+            message,
+            offset,
+            len,
+            width: input[offset..end].chars().count(),
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            snippet: snippet.to_string(),
+        }
+    }
+
+    /// Moves an error found by parsing `part` on its own to where `part` is in `input`,
+    /// for errors from helpers that only see a single line or field
+    pub fn within(self, input: &str, part: &str) -> Self {
+        let offset = offset_in(input, part) + self.offset;
+        Self::at(input, offset, self.len, self.message)
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// The offending line with the offending text underlined
+    pub fn underline(&self) -> String {
+        format!(
+            "{}\n{}{}",
+            self.snippet,
+            " ".repeat(self.column - 1),
+            "^".repeat(self.width.max(1))
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// The byte offset of `span` in `input`, or the end of the input if `span` isn't a slice of it
+fn offset_in(input: &str, span: &str) -> usize {
+    let offset = (span.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+    debug_assert!(offset <= input.len(), "the span is not part of the input");
+    offset.min(input.len())
+}
+
+/// Turns a failure into a [`ParseError`] at the text that caused it,
+/// much like `anyhow::Context` adds a message
+pub trait ParseContext<T> {
+    fn at(self, input: &str, span: &str, message: impl Display) -> Result<T, ParseError>;
+}
+
+impl<T> ParseContext<T> for Option<T> {
+    fn at(self, input: &str, span: &str, message: impl Display) -> Result<T, ParseError> {
+        self.ok_or_else(|| ParseError::new(input, span, message))
+    }
+}
+
+impl<T, E: Display> ParseContext<T> for Result<T, E> {
+    fn at(self, input: &str, span: &str, message: impl Display) -> Result<T, ParseError> {
+        self.map_err(|err| ParseError::new(input, span, format!("{}: {}", message, err)))
+    }
+}
+
+/// Parses slices of one input, positioning every error in that input
+#[derive(Clone, Copy)]
+pub struct Parser<'a> {
+    input: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self { input }
+    }
+
+    /// An error at `span`, which must be a slice of the input
+    pub fn error(&self, span: &str, message: impl Display) -> ParseError {
+        ParseError::new(self.input, span, message)
+    }
+
+    /// Parses `text` with a helper that only sees `text`, moving its errors into place in the input
+    pub fn within<T>(
+        &self,
+        text: &'a str,
+        parse: impl FnOnce(&'a str) -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        parse(text).map_err(|err| err.within(self.input, text))
+    }
+
+    /// The text before and after the first `delimiter`
+    pub fn split_once(
+        &self,
+        text: &'a str,
+        delimiter: char,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        text.split_once(delimiter)
+            .at(self.input, text, format!("expected '{}'", delimiter))
+    }
+
+    /// The blocks of lines that are separated by blank lines, with either line ending
+    pub fn sections(&self, text: &'a str) -> impl Iterator<Item = &'a str> + use<'a> {
+        let mut lines = text.lines().peekable();
+        iter::from_fn(move || {
+            while lines.next_if(|line| line.is_empty()).is_some() {}
+            let first = lines.next()?;
+            let mut last = first;
+            while let Some(line) = lines.next_if(|line| !line.is_empty()) {
+                last = line;
+            }
+            Some(&text[offset_in(text, first)..offset_in(text, last) + last.len()])
+        })
+    }
+
+    /// Exactly `N` items, or an error at `span` saying how many `what` were expected
+    pub fn exactly<T, const N: usize>(
+        &self,
+        items: impl IntoIterator<Item = T>,
+        span: &str,
+        what: &str,
+    ) -> Result<[T; N], ParseError> {
+        items
+            .into_iter()
+            .collect_array()
+            .at(self.input, span, format!("expected {} {}", N, what))
+    }
+
+    pub fn number<T>(&self, text: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        text.parse().at(self.input, text, "invalid number")
+    }
+
+    /// Numbers separated by `separator`, with any whitespace around them
+    pub fn numbers<T>(&self, text: &str, separator: char) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        text.split(separator)
+            .map(|number| self.number(number.trim()))
+            .collect()
+    }
+
+    /// Numbers separated by any amount of whitespace
+    pub fn spaced_numbers<T>(&self, text: &str) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        text.split_whitespace()
+            .map(|number| self.number(number))
+            .collect()
+    }
+
+    /// An inclusive range written as `start-end`
+    pub fn range<T>(&self, text: &str) -> Result<RangeInclusive<T>, ParseError>
+    where
+        T: FromStr + PartialOrd,
+        T::Err: Display,
+    {
+        let (start, end) =
+            text.split_once('-')
+                .at(self.input, text, "expected a range like '3-5'")?;
+        let (start, end) = (self.number(start.trim())?, self.number(end.trim())?);
+        if end < start {
+            return Err(self.error(text, "the range ends before it starts"));
+        }
+        Ok(start..=end)
+    }
+
+    /// One cell for each character, where `cell` returns `None` for the characters
+    /// that aren't allowed and `expected` describes the ones that are
+    pub fn cells<T>(
+        &self,
+        text: &str,
+        cell: impl Fn(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Vec<T>, ParseError> {
+        text.char_indices()
+            .map(|(i, c)| cell(c).at(self.input, &text[i..i + c.len_utf8()], expected))
+            .collect()
+    }
+
+    /// A grid of cells with one row per line, see [`Parser::cells`].
+    /// Every row must be as long as the first.
+    pub fn grid<T>(
+        &self,
+        text: &str,
+        cell: impl Fn(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Vec<Vec<T>>, ParseError> {
+        let mut width = None;
+        text.lines()
+            .map(|line| {
+                let row = self.cells(line, &cell, expected)?;
+                let width = *width.get_or_insert(row.len());
+                if row.len() != width {
+                    return Err(self.error(
+                        line,
+                        format!(
+                            "expected {} cells like the first row, got {}",
+                            width,
+                            row.len()
+                        ),
+                    ));
+                }
+                Ok(row)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_at_the_offending_text() {
+        let input = "L68\nR30\nX12\n";
+        let err = ParseError::new(input, &input[8..9], "invalid direction");
+        assert_eq!(err.line(), 3);
+        assert_eq!(err.column(), 1);
+        assert_eq!(err.to_string(), "line 3, column 1: invalid direction");
+        assert_eq!(err.underline(), "X12\n^");
+    }
+
+    #[test]
+    fn moves_errors_from_a_line_into_the_input() {
+        let input = "1,2,3\r\n4,x5,6\r\n";
+        let line = input.lines().nth(1).unwrap();
+        let err = "x5"
+            .parse::<u32>()
+            .at(line, &line[2..4], "invalid coordinate");
+        let err = err.unwrap_err().within(input, line);
+        assert_eq!((err.line(), err.column()), (2, 3));
+        assert_eq!(err.underline(), "4,x5,6\n  ^^");
+        assert_eq!(
+            err.message(),
+            "invalid coordinate: invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn parses_the_common_shapes() {
+        let input = "3-5\n1, 2,3\n\n#.#\n.#.";
+        let parser = Parser::new(input);
+        let [ranges, grid] = parser
+            .exactly(parser.sections(input), input, "sections")
+            .unwrap();
+        let [range, numbers] = parser.exactly(ranges.lines(), ranges, "lines").unwrap();
+        assert_eq!(parser.range::<u8>(range), Ok(3..=5));
+        assert_eq!(parser.numbers::<u8>(numbers, ','), Ok(vec![1, 2, 3]));
+        let grid = parser.grid(
+            grid,
+            |c| (c == '#' || c == '.').then_some(c == '#'),
+            "'#' or '.'",
+        );
+        assert_eq!(grid.unwrap()[1], [false, true, false]);
+    }
+
+    #[test]
+    fn sections_split_on_blank_lines_with_either_line_ending() {
+        let input = "1\r\n2\r\n\r\n3\r\n\r\n\r\n4\r\n";
+        let sections: Vec<_> = Parser::new(input).sections(input).collect();
+        assert_eq!(sections, ["1\r\n2", "3", "4"]);
+        let input = input.replace("\r\n", "\n");
+        let sections: Vec<_> = Parser::new(&input).sections(&input).collect();
+        assert_eq!(sections, ["1\n2", "3", "4"]);
+    }
+
+    #[test]
+    fn grids_must_be_rectangular() {
+        let input = "#.#\r\n.#.\r\n#.\r\n";
+        let err = Parser::new(input)
+            .grid(input, |c| Some(c == '#'), "'#' or '.'")
+            .unwrap_err();
+        assert_eq!((err.line(), err.column()), (3, 1));
+        assert_eq!(err.message(), "expected 3 cells like the first row, got 2");
+    }
+
+    #[test]
+    fn combinators_point_at_the_bad_text() {
+        let input = "1 2 x3\n4-\n#?";
+        let parser = Parser::new(input);
+        let lines: Vec<_> = input.lines().collect();
+        let err = parser.spaced_numbers::<u8>(lines[0]).unwrap_err();
+        assert_eq!((err.line(), err.column()), (1, 5));
+        let err = parser.range::<u8>(lines[1]).unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 3));
+        let err = Parser::new("5-3").range::<u8>("5-3").unwrap_err();
+        assert_eq!(err.message(), "the range ends before it starts");
+        let err = parser.cells(lines[2], |c| (c == '#').then_some(()), "'#'");
+        assert_eq!(err.unwrap_err().underline(), "#?\n ^");
+        let err = parser.exactly::<_, 2>(lines[0].split(' '), lines[0], "numbers");
+        assert_eq!(err.unwrap_err().message(), "expected 2 numbers");
+    }
+
+    #[test]
+    fn underlines_the_end_of_a_line_for_missing_text() {
+        let input = "3x4: 1 2\n5x5";
+        let line = input.lines().nth(1).unwrap();
+        let err = None::<()>.at(input, &line[line.len()..], "missing ':'");
+        let err = err.unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 4));
+        assert_eq!(err.underline(), "5x5\n   ^");
+    }
+}
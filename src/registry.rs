@@ -0,0 +1,292 @@
+use crate::generate::{Generator, InputGenerator};
+use crate::memory::{AllocStats, Measurement};
+use crate::panics;
+use crate::parse::ParseError;
+use crate::solution::{Answer, Example, IntoAnswer, Param, Part, Solution};
+use anyhow::{Context, Result, anyhow};
+use std::time::{Duration, Instant};
+
+/// The answer to one part, or the error that prevented it,
+/// and how long parsing the input and solving the part took
+pub struct PartResult {
+    pub part: Part,
+    pub answer: Result<Answer>,
+    pub parse_elapsed: Duration,
+    pub elapsed: Duration,
+    /// What parsing and solving allocated, when built with the `count-allocations` feature
+    pub memory: Option<AllocStats>,
+}
+
+impl PartResult {
+    pub fn total_elapsed(&self) -> Duration {
+        self.parse_elapsed + self.elapsed
+    }
+}
+
+/// Solves the selected parts of an input, handing over each part's result once it is solved
+type SolveFn = fn(&str, Option<Part>, &[Param], &mut dyn FnMut(PartResult));
+
+/// A day's solution with its type erased, so that solutions can be stored together
+#[derive(Clone, Copy)]
+pub struct Solver {
+    solve: SolveFn,
+    params: fn() -> Vec<(&'static str, String)>,
+    examples: &'static [Example],
+    has_part2: bool,
+}
+
+impl Solver {
+    pub const fn of<T: Solution>() -> Self {
+        Self {
+            solve: solve::<T>,
+            params: || T::default().params(),
+            examples: T::EXAMPLES,
+            has_part2: T::HAS_PART2,
+        }
+    }
+
+    /// Solves the selected parts of a day's input.
+    /// Parameters that the day doesn't have are ignored, so one list can be shared by several days.
+    pub fn solve(&self, input: &str, part: Option<Part>, params: &[Param]) -> Vec<PartResult> {
+        let mut results = Vec::new();
+        self.solve_each(input, part, params, &mut |result| results.push(result));
+        results
+    }
+
+    /// Like [`Solver::solve`], but hands over each part's result as soon as the part is solved
+    pub fn solve_each(
+        &self,
+        input: &str,
+        part: Option<Part>,
+        params: &[Param],
+        on_part: &mut dyn FnMut(PartResult),
+    ) {
+        (self.solve)(input, part, params, on_part)
+    }
+
+    /// The parameters the day can be tuned with and their default values
+    pub fn params(&self) -> Vec<(&'static str, String)> {
+        (self.params)()
+    }
+
+    pub fn examples(&self) -> &'static [Example] {
+        self.examples
+    }
+
+    /// The parts the puzzle has, which is only part 1 on the last day of a year
+    pub fn parts(&self) -> impl Iterator<Item = Part> + use<> {
+        let has_part2 = self.has_part2;
+        Part::ALL
+            .into_iter()
+            .filter(move |&part| part == Part::One || has_part2)
+    }
+
+    pub fn has_param(&self, name: &str) -> bool {
+        self.params().iter().any(|&(param, _)| param == name)
+    }
+}
+
+/// A puzzle with a solution, which its day module adds to the registry with `inventory::submit!`
+pub struct Puzzle {
+    pub year: u16,
+    pub day: usize,
+    pub title: &'static str,
+    pub solver: Solver,
+    /// Makes random inputs for the day, if it has a generator
+    pub generator: Option<InputGenerator>,
+    /// A slow but simple solution to check the real one against, if the day has one
+    pub reference: Option<Solver>,
+}
+
+impl Puzzle {
+    pub const fn new<T: Solution>(year: u16, day: usize, title: &'static str) -> Self {
+        Self {
+            year,
+            day,
+            title,
+            solver: Solver::of::<T>(),
+            generator: None,
+            reference: None,
+        }
+    }
+
+    /// Adds a generator of random inputs to the puzzle
+    pub const fn with_generator<G: Generator>(mut self) -> Self {
+        self.generator = Some(InputGenerator::of::<G>());
+        self
+    }
+
+    /// Adds a reference solution, which `crosscheck` compares the solution against
+    pub const fn with_reference<T: Solution>(mut self) -> Self {
+        self.reference = Some(Solver::of::<T>());
+        self
+    }
+}
+
+inventory::collect!(Puzzle);
+
+/// Every registered puzzle, ordered by year and day
+pub fn puzzles() -> Vec<&'static Puzzle> {
+    let mut puzzles: Vec<_> = inventory::iter::<Puzzle>().collect();
+    puzzles.sort_by_key(|puzzle| (puzzle.year, puzzle.day));
+    puzzles
+}
+
+/// The most recent year with any solutions
+pub fn latest_year() -> Option<u16> {
+    inventory::iter::<Puzzle>().map(|puzzle| puzzle.year).max()
+}
+
+/// The days of `year` that have a solution, in order
+pub fn days(year: u16) -> Vec<usize> {
+    puzzles()
+        .into_iter()
+        .filter(|puzzle| puzzle.year == year)
+        .map(|puzzle| puzzle.day)
+        .collect()
+}
+
+pub fn puzzle(year: u16, day: usize) -> Result<&'static Puzzle> {
+    inventory::iter::<Puzzle>()
+        .find(|puzzle| puzzle.year == year && puzzle.day == day)
+        .with_context(|| format!("No solution for day {} of {}", day, year))
+}
+
+/// Parses the input once and solves the selected parts from it, handing each part to `on_part`.
+/// When both parts are solved, they share the same parse time and parse allocations.
+pub fn solve<T: Solution>(
+    input: &str,
+    part: Option<Part>,
+    params: &[Param],
+    on_part: &mut dyn FnMut(PartResult),
+) {
+    let measurement = Measurement::start();
+    let now = Instant::now();
+    let parsed = configure::<T>(params).and_then(|solution| {
+        let parsed = panics::catch(|| solution.parse(input)).context("Failed to parse input")?;
+        Ok((solution, parsed))
+    });
+    let parse_elapsed = now.elapsed();
+    let parse_memory = measurement.as_ref().map(Measurement::stop);
+
+    let parts = Part::ALL
+        .into_iter()
+        .filter(|&p| p.is_selected(part) && (p == Part::One || T::HAS_PART2));
+    match parsed {
+        Ok((solution, parsed)) => {
+            for part in parts {
+                let part_measurement = measurement.as_ref().map(Measurement::resume);
+                let (answer, elapsed) = match part {
+                    Part::One => time_part(|| solution.part1(&parsed)),
+                    Part::Two => time_part(|| solution.part2(&parsed)),
+                };
+                let part_memory = part_measurement.as_ref().map(Measurement::stop);
+                on_part(PartResult {
+                    part,
+                    answer,
+                    parse_elapsed,
+                    elapsed,
+                    memory: parse_memory
+                        .zip(part_memory)
+                        .map(|(parse, part)| parse.then(part)),
+                });
+            }
+        }
+        Err(err) => {
+            for part in parts {
+                on_part(PartResult {
+                    part,
+                    answer: Err(copy_error(&err)),
+                    parse_elapsed,
+                    elapsed: Duration::ZERO,
+                    memory: parse_memory,
+                });
+            }
+        }
+    }
+}
+
+/// Copies an error for each part that it stopped, keeping any [`ParseError`] at the bottom
+/// of the chain so that the runner can still point at the line that failed to parse
+fn copy_error(err: &anyhow::Error) -> anyhow::Error {
+    let mut chain: Vec<_> = err.chain().collect();
+    let root = chain.pop().expect("an error chain is never empty");
+    let copy = match root.downcast_ref::<ParseError>() {
+        Some(parse_error) => anyhow!(parse_error.clone()),
+        None => anyhow!("{}", root),
+    };
+    chain
+        .into_iter()
+        .rev()
+        .fold(copy, |copy, cause| copy.context(cause.to_string()))
+}
+
+/// Creates the solution with the parameters that apply to it overridden
+fn configure<T: Solution>(params: &[Param]) -> Result<T> {
+    let mut solution = T::default();
+    let names: Vec<_> = solution
+        .params()
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect();
+    for param in params {
+        if names.contains(&param.name.as_str()) {
+            solution
+                .set_param(&param.name, &param.value)
+                .with_context(|| format!("Invalid parameter {}", param))?;
+        }
+    }
+    Ok(solution)
+}
+
+fn time_part<F, T>(solve: F) -> (Result<Answer>, Duration)
+where
+    F: FnOnce() -> Result<T>,
+    T: IntoAnswer,
+{
+    let now = Instant::now();
+    let answer = panics::catch(solve);
+    let elapsed = now.elapsed();
+    (answer.map(IntoAnswer::into_answer), elapsed)
+}
+
+/// Solves every example of a day and checks the answers given for it
+#[cfg(test)]
+pub(crate) fn check_examples<T: Solution>() {
+    assert!(!T::EXAMPLES.is_empty(), "no examples to check");
+    for (i, example) in T::EXAMPLES.iter().enumerate() {
+        for result in Solver::of::<T>().solve(example.input, None, &example.params()) {
+            let Some(expected) = example.answer(result.part) else {
+                continue;
+            };
+            match result.answer {
+                Ok(answer) => assert_eq!(
+                    answer.value,
+                    expected,
+                    "wrong answer to part {} of example {}",
+                    result.part,
+                    i + 1
+                ),
+                Err(err) => panic!("part {} of example {}: {:#}", result.part, i + 1, err),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn puzzles_are_registered_once() {
+        let puzzles = puzzles();
+        for pair in puzzles.windows(2) {
+            assert!(
+                (pair[0].year, pair[0].day) != (pair[1].year, pair[1].day),
+                "day {} of {} is registered twice",
+                pair[1].day,
+                pair[1].year
+            );
+        }
+    }
+}
@@ -0,0 +1,531 @@
+use crate::input::Input;
+use crate::table::Table;
+use anyhow::Result;
+use aoc2025::memory::AllocStats;
+use aoc2025::parse::ParseError;
+use aoc2025::registry::{PartResult, Puzzle};
+use aoc2025::solution::Answer;
+use clap::ValueEnum;
+use itertools::Itertools;
+use serde::Serialize;
+use std::fmt::{self, Display, Write};
+use std::iter;
+use std::time::Duration;
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+    Markdown,
+}
+
+/// One solved part, flattened for the machine-readable formats
+#[derive(Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: usize,
+    pub part: u8,
+    pub input: String,
+    pub answer: Option<String>,
+    /// How the answer was found, for the parts that explain their answers
+    pub explanation: Option<String>,
+    /// Parsing and solving together, where the parts of an input share one parse
+    pub duration_ns: u64,
+    pub parse_ns: u64,
+    /// Allocations made while parsing and solving, when they are counted
+    pub allocations: Option<u64>,
+    pub allocated_bytes: Option<u64>,
+    pub peak_bytes: Option<u64>,
+    pub error: Option<String>,
+}
+
+impl Record {
+    pub fn new(puzzle: &Puzzle, input: &Input, result: &PartResult) -> Self {
+        let (answer, explanation, error) = match &result.answer {
+            Ok(answer) => (Some(answer.value.clone()), answer.explanation.clone(), None),
+            Err(err) => (None, None, Some(format!("{:#}", err))),
+        };
+        Self {
+            year: puzzle.year,
+            day: puzzle.day,
+            part: result.part as u8,
+            input: input.name.clone(),
+            answer,
+            explanation,
+            duration_ns: result.total_elapsed().as_nanos() as u64,
+            parse_ns: result.parse_elapsed.as_nanos() as u64,
+            allocations: result.memory.map(|memory| memory.allocations),
+            allocated_bytes: result.memory.map(|memory| memory.bytes),
+            peak_bytes: result.memory.map(|memory| memory.peak),
+            error,
+        }
+    }
+}
+
+/// Prints one day's results as text
+pub fn print_day(day: usize, inputs: &[Input], results: &[Vec<PartResult>]) {
+    print!(
+        "{}",
+        DayReport {
+            day,
+            inputs,
+            results
+        }
+    );
+}
+
+/// One day's results as text, with one column per input when there are several.
+/// The parts of an input share its parsing, so the parse time is shown once and the parts show their solve time.
+struct DayReport<'a> {
+    day: usize,
+    inputs: &'a [Input],
+    results: &'a [Vec<PartResult>],
+}
+
+impl Display for DayReport<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "--- Day {} ---", self.day)?;
+        match self.results {
+            [results] => {
+                // an input that couldn't be read was never parsed
+                if let Some(result) = results
+                    .first()
+                    .filter(|result| !result.parse_elapsed.is_zero())
+                {
+                    writeln!(f, "Parse: {:.2?}", result.parse_elapsed)?;
+                }
+                for result in results {
+                    match &result.answer {
+                        Ok(answer) => {
+                            // a drawing starts on the line below, so that its rows line up
+                            let value = match answer.is_multiline() {
+                                true => "",
+                                false => answer.value.as_str(),
+                            };
+                            writeln!(
+                                f,
+                                "Part {}: {}\t{:.2?}{}",
+                                result.part,
+                                value,
+                                result.elapsed,
+                                memory_summary(result.memory)
+                            )?;
+                            if let Some(details) = details(answer) {
+                                writeln!(f, "{}", details)?;
+                            }
+                        }
+                        Err(err) => writeln!(f, "Part {}: error: {:#}", result.part, err)?,
+                    }
+                }
+                write_underlines(f, results)?;
+            }
+            results => {
+                // one column per input, one row per part
+                let mut table = Table::new(
+                    iter::once("").chain(self.inputs.iter().map(|input| input.name.as_str())),
+                );
+                let parse_times = results.iter().map(|results| match results.first() {
+                    Some(result) => format!("{:.2?}", result.parse_elapsed),
+                    None => String::new(),
+                });
+                table.push(iter::once("Parse:".to_string()).chain(parse_times));
+                for (i, result) in results[0].iter().enumerate() {
+                    let cells = results.iter().map(|results| table_cell(&results[i]));
+                    table.push(iter::once(format!("Part {}:", result.part)).chain(cells));
+                }
+                write!(f, "{}", table)?;
+                // the table only has room for the answers themselves
+                for (input, results) in self.inputs.iter().zip(results) {
+                    let explained: Vec<_> = results
+                        .iter()
+                        .filter_map(|result| {
+                            let answer = result.answer.as_ref().ok()?;
+                            Some((result.part, answer.explanation.as_ref()?))
+                        })
+                        .collect();
+                    if !explained.is_empty()
+                        || results
+                            .iter()
+                            .any(|result| underline(&result.answer).is_some())
+                    {
+                        writeln!(f, "{}:", input.name)?;
+                        for (part, explanation) in explained {
+                            writeln!(f, "  Part {}: {}", part, explanation)?;
+                        }
+                        write_underlines(f, results)?;
+                    }
+                }
+            }
+        }
+        writeln!(f)
+    }
+}
+
+/// Shows where parsing failed, once for the parts that share the failure
+fn write_underlines(f: &mut fmt::Formatter, results: &[PartResult]) -> fmt::Result {
+    let underlines: Vec<_> = results
+        .iter()
+        .filter_map(|result| underline(&result.answer))
+        .dedup()
+        .collect();
+    for underline in underlines {
+        writeln!(f, "{}", underline)?;
+    }
+    Ok(())
+}
+
+/// A drawing and the explanation of an answer, indented to go below the part
+fn details(answer: &Answer) -> Option<String> {
+    let drawing = answer.is_multiline().then_some(answer.value.as_str());
+    let lines: Vec<_> = drawing
+        .into_iter()
+        .chain(answer.explanation.as_deref())
+        .flat_map(str::lines)
+        .map(|line| format!("    {}", line))
+        .collect();
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
+/// The line that failed to parse with the offending text underlined, indented to go below the error
+pub fn underline(answer: &Result<Answer>) -> Option<String> {
+    let err = answer.as_ref().err()?;
+    let parse_error = err
+        .chain()
+        .find_map(|cause| cause.downcast_ref::<ParseError>())?;
+    Some(
+        parse_error
+            .underline()
+            .lines()
+            .map(|line| format!("    {}", line))
+            .join("\n"),
+    )
+}
+
+fn table_cell(result: &PartResult) -> String {
+    match &result.answer {
+        Ok(answer) => format!(
+            "{} ({:.2?}{})",
+            answer.value,
+            result.elapsed,
+            memory_summary(result.memory)
+        ),
+        Err(err) => format!("error: {:#}", err),
+    }
+}
+
+/// The allocations of a part to show after its timing, if they were counted
+fn memory_summary(memory: Option<AllocStats>) -> String {
+    match memory {
+        Some(memory) => format!(
+            ", {} allocations, {} allocated, {} peak",
+            memory.allocations,
+            format_size(memory.bytes),
+            format_size(memory.peak)
+        ),
+        None => String::new(),
+    }
+}
+
+pub fn format_size(bytes: u64) -> String {
+    match bytes {
+        bytes if bytes >= 1 << 30 => format!("{:.1} GiB", bytes as f64 / (1u64 << 30) as f64),
+        bytes if bytes >= 1 << 20 => format!("{:.1} MiB", bytes as f64 / (1u64 << 20) as f64),
+        bytes => format!("{:.1} KiB", bytes as f64 / 1024.0),
+    }
+}
+
+/// Lists every part that failed, so that errors aren't lost among the answers
+pub fn print_summary(records: &[Record], wall_time: Duration) {
+    let solve_time: Duration = records
+        .iter()
+        .map(|record| Duration::from_nanos(record.duration_ns - record.parse_ns))
+        .sum();
+    // the parts of an input are consecutive and share one parse
+    let parse_time: Duration = records
+        .iter()
+        .dedup_by(|a, b| {
+            (a.year, a.day, &a.input, a.parse_ns) == (b.year, b.day, &b.input, b.parse_ns)
+        })
+        .map(|record| Duration::from_nanos(record.parse_ns))
+        .sum();
+    let part_time = parse_time + solve_time;
+    println!(
+        "Total time: {:.2?} wall, {:.2?} summed over parts",
+        wall_time, part_time
+    );
+    let failures: Vec<_> = records
+        .iter()
+        .filter_map(|record| Some((record, record.error.as_ref()?)))
+        .collect();
+    if failures.is_empty() {
+        println!("All {} parts succeeded", records.len());
+        return;
+    }
+    println!("{} of {} parts failed:", failures.len(), records.len());
+    for (record, error) in failures {
+        println!(
+            "  Day {} part {} ({}): {}",
+            record.day, record.part, record.input, error
+        );
+    }
+}
+
+/// Writes the records in one of the machine-readable formats
+pub fn write(format: Format, records: &[Record]) -> Result<()> {
+    print!("{}", render(format, records)?);
+    Ok(())
+}
+
+/// The records in one of the machine-readable formats
+fn render(format: Format, records: &[Record]) -> Result<String> {
+    let mut out = String::new();
+    match format {
+        Format::Text => unreachable!("text output is printed while running"),
+        Format::Json => writeln!(out, "{}", serde_json::to_string_pretty(records)?)?,
+        Format::Csv => {
+            writeln!(
+                out,
+                "year,day,part,input,answer,explanation,duration_ns,parse_ns,\
+                 allocations,allocated_bytes,peak_bytes,error"
+            )?;
+            let optional =
+                |value: Option<u64>| value.map(|value| value.to_string()).unwrap_or_default();
+            for record in records {
+                writeln!(
+                    out,
+                    "{},{},{},{},{},{},{},{},{},{},{},{}",
+                    record.year,
+                    record.day,
+                    record.part,
+                    csv_field(&record.input),
+                    csv_field(record.answer.as_deref().unwrap_or_default()),
+                    csv_field(record.explanation.as_deref().unwrap_or_default()),
+                    record.duration_ns,
+                    record.parse_ns,
+                    optional(record.allocations),
+                    optional(record.allocated_bytes),
+                    optional(record.peak_bytes),
+                    csv_field(record.error.as_deref().unwrap_or_default()),
+                )?;
+            }
+        }
+        Format::Markdown => {
+            // the allocation columns are only shown when allocations were counted
+            let counted = records.iter().any(|record| record.allocations.is_some());
+            let (memory_header, memory_align) = match counted {
+                true => (" Allocations | Allocated | Peak |", " --: | --: | --: |"),
+                false => ("", ""),
+            };
+            // and the explanation column when any part explained its answer
+            let explained = records.iter().any(|record| record.explanation.is_some());
+            let (explanation_header, explanation_align) = match explained {
+                true => (" Explanation |", " --- |"),
+                false => ("", ""),
+            };
+            writeln!(
+                out,
+                "| Year | Day | Part | Input | Answer |{} Time | Parse |{} Error |",
+                explanation_header, memory_header
+            )?;
+            writeln!(
+                out,
+                "| --: | --: | --: | --- | --- |{} --: | --: |{} --- |",
+                explanation_align, memory_align
+            )?;
+            for record in records {
+                let memory = match (
+                    record.allocations,
+                    record.allocated_bytes,
+                    record.peak_bytes,
+                ) {
+                    (Some(allocations), Some(bytes), Some(peak)) => format!(
+                        " {} | {} | {} |",
+                        allocations,
+                        format_size(bytes),
+                        format_size(peak)
+                    ),
+                    _ if counted => " | | |".to_string(),
+                    _ => String::new(),
+                };
+                let explanation = match explained {
+                    true => format!(
+                        " {} |",
+                        markdown_cell(record.explanation.as_deref().unwrap_or_default())
+                    ),
+                    false => String::new(),
+                };
+                writeln!(
+                    out,
+                    "| {} | {} | {} | {} | {} |{} {:.2?} | {:.2?} |{} {} |",
+                    record.year,
+                    record.day,
+                    record.part,
+                    markdown_cell(&record.input),
+                    markdown_cell(record.answer.as_deref().unwrap_or_default()),
+                    explanation,
+                    Duration::from_nanos(record.duration_ns),
+                    Duration::from_nanos(record.parse_ns),
+                    memory,
+                    markdown_cell(record.error.as_deref().unwrap_or_default()),
+                )?;
+            }
+        }
+    }
+    Ok(out)
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn markdown_cell(cell: &str) -> String {
+    cell.replace('|', "\\|").replace('\n', "<br>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc2025::solution::Part;
+
+    fn input(name: &str) -> Input {
+        Input {
+            name: name.to_string(),
+            contents: String::new(),
+            params: Vec::new(),
+        }
+    }
+
+    fn result(part: Part, answer: Answer, micros: u64) -> PartResult {
+        PartResult {
+            part,
+            answer: Ok(answer),
+            parse_elapsed: Duration::from_micros(1),
+            elapsed: Duration::from_micros(micros),
+            memory: None,
+        }
+    }
+
+    fn drawing() -> Answer {
+        Answer::drawing([[true, false, false, true], [true, true, true, true]])
+    }
+
+    fn record(answer: &str, explanation: Option<&str>) -> Record {
+        Record {
+            year: 2025,
+            day: 13,
+            part: 1,
+            input: "input.txt".to_string(),
+            answer: Some(answer.to_string()),
+            explanation: explanation.map(str::to_string),
+            duration_ns: 2000,
+            parse_ns: 1000,
+            allocations: None,
+            allocated_bytes: None,
+            peak_bytes: None,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn drawings_and_explanations_go_below_their_part() {
+        let results = [vec![
+            result(Part::One, drawing(), 5),
+            result(Part::Two, Answer::explained(42, "6 times 7"), 7),
+        ]];
+        let report = DayReport {
+            day: 13,
+            inputs: &[input("input.txt")],
+            results: &results,
+        };
+        let lines: Vec<_> = report.to_string().lines().map(str::to_string).collect();
+        assert_eq!(
+            lines,
+            [
+                "--- Day 13 ---",
+                "Parse: 1.00µs",
+                "Part 1: \t5.00µs",
+                "    #..#",
+                "    ####",
+                "Part 2: 42\t7.00µs",
+                "    6 times 7",
+                "",
+            ]
+        );
+    }
+
+    #[test]
+    fn drawings_fill_their_cell_and_explanations_follow_the_table() {
+        let results = [
+            vec![result(Part::One, drawing(), 5)],
+            vec![result(Part::One, Answer::explained(42, "6 times 7"), 7)],
+        ];
+        let report = DayReport {
+            day: 13,
+            inputs: &[input("a.txt"), input("b.txt")],
+            results: &results,
+        };
+        let lines: Vec<_> = report.to_string().lines().map(str::to_string).collect();
+        assert_eq!(
+            lines,
+            [
+                "--- Day 13 ---",
+                "         a.txt          b.txt",
+                "Parse:   1.00µs         1.00µs",
+                "Part 1:  #..#           42 (7.00µs)",
+                "         #### (5.00µs)",
+                "b.txt:",
+                "  Part 1: 6 times 7",
+                "",
+            ]
+        );
+    }
+
+    #[test]
+    fn details_are_indented_below_the_part() {
+        assert_eq!(details(&Answer::new(42)), None);
+        assert_eq!(
+            details(&Answer::explained(42, "6 times 7\nand no more")).as_deref(),
+            Some("    6 times 7\n    and no more")
+        );
+        let mut answer = drawing();
+        answer.explanation = Some("reads HI".to_string());
+        assert_eq!(
+            details(&answer).as_deref(),
+            Some("    #..#\n    ####\n    reads HI")
+        );
+    }
+
+    #[test]
+    fn multi_line_values_are_escaped() {
+        let records = [record("#..#\n####", Some("reads \"H\", mostly"))];
+        let csv = render(Format::Csv, &records).unwrap();
+        assert_eq!(
+            csv.lines().skip(1).collect::<Vec<_>>(),
+            [
+                "2025,13,1,input.txt,\"#..#",
+                "####\",\"reads \"\"H\"\", mostly\",2000,1000,,,,"
+            ]
+        );
+
+        let records = [record("#|.#\n####", Some("a\nb")), record("7", None)];
+        let markdown = render(Format::Markdown, &records).unwrap();
+        let lines: Vec<_> = markdown.lines().collect();
+        assert_eq!(
+            lines[0],
+            "| Year | Day | Part | Input | Answer | Explanation | Time | Parse | Error |"
+        );
+        assert_eq!(
+            lines[2],
+            "| 2025 | 13 | 1 | input.txt | #\\|.#<br>#### | a<br>b | 2.00µs | 1.00µs |  |"
+        );
+        assert_eq!(
+            lines[3],
+            "| 2025 | 13 | 1 | input.txt | 7 |  | 2.00µs | 1.00µs |  |"
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use crate::input;
+use anyhow::{Context, Result, ensure};
+use aoc2025::registry;
+use std::fs;
+use std::path::Path;
+
+const TEMPLATE: &str = r#"use crate::parse::Parser;
+use crate::registry::Puzzle;
+use crate::solution::{Example, Solution};
+use anyhow::Result;
+
+#[derive(Default)]
+pub struct Day{day};
+
+inventory::submit! {
+    Puzzle::new::<Day{day}>({year}, {day}, "{title}")
+}
+
+impl Solution for Day{day} {
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../../examples/{year}/day{day}.txt"),
+        params: &[],
+        part1: None,
+        part2: None,
+    }];
+
+    type Input = Vec<Vec<usize>>;
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let parser = Parser::new(input);
+        let rows = input
+            .lines()
+            .map(|line| parser.spaced_numbers(line))
+            .collect::<Result<_, _>>()?;
+        Ok(rows)
+    }
+
+    type Part1Output = usize;
+    fn part1(&self, _input: &Self::Input) -> Result<Self::Part1Output> {
+        todo!()
+    }
+
+    type Part2Output = usize;
+    fn part2(&self, _input: &Self::Input) -> Result<Self::Part2Output> {
+        todo!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::check_examples;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day{day}>();
+    }
+}
+"#;
+
+const FUZZ_TEMPLATE: &str = r#"#![no_main]
+
+use aoc2025::y{year}::day{day}::Day{day};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| aoc2025_fuzz::solve::<Day{day}>(input));
+"#;
+
+const FUZZ_BIN: &str = r#"
+[[bin]]
+name = "y{year}_day{day}"
+path = "fuzz_targets/y{year}_day{day}.rs"
+test = false
+doc = false
+bench = false
+"#;
+
+/// Adds a day: writes its module and fuzz target from templates, declares them
+/// and creates empty input and example files to paste the puzzle into
+pub fn new_day(year: u16, day: usize, title: &str) -> Result<()> {
+    ensure!(
+        Path::new("src/lib.rs").exists() && Path::new("src/registry.rs").exists(),
+        "`new` must be run from the root of the repository"
+    );
+    ensure!(
+        registry::puzzle(year, day).is_err(),
+        "Day {} of {} already has a solution",
+        day,
+        year
+    );
+    let module = format!("src/y{}/day{}.rs", year, day);
+    ensure!(!Path::new(&module).exists(), "{} already exists", module);
+
+    let fill = |template: &str| {
+        template
+            .replace("{year}", &year.to_string())
+            .replace("{day}", &day.to_string())
+            .replace("{title}", &title.replace('"', "\\\""))
+    };
+    let source = fill(TEMPLATE);
+    let example = format!("examples/{}/day{}.txt", year, day);
+    create(&module, &source)?;
+    create(&example, "")?;
+    create(&input::default_path(year, day).display().to_string(), "")?;
+
+    let year_module = format!("src/y{}.rs", year);
+    if !Path::new(&year_module).exists() {
+        let header = format!(
+            "//! [Advent of Code {0}](https://adventofcode.com/{0})\n\n",
+            year
+        );
+        create(&year_module, &header)?;
+        declare("src/lib.rs", "y", year.into())?;
+    }
+    declare(&year_module, "day", day)?;
+
+    let target = format!("fuzz/fuzz_targets/y{}_day{}.rs", year, day);
+    create(&target, &fill(FUZZ_TEMPLATE))?;
+    edit("fuzz/Cargo.toml", |manifest| {
+        Ok(manifest.to_string() + &fill(FUZZ_BIN))
+    })?;
+    println!(
+        "Added day {} of {}: paste the example into {}",
+        day, year, example
+    );
+    Ok(())
+}
+
+/// Creates a file unless it already exists, so that no input is ever overwritten
+fn create(path: &str, contents: &str) -> Result<()> {
+    if Path::new(path).exists() {
+        println!("Kept existing {}", path);
+        return Ok(());
+    }
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, contents).with_context(|| format!("Failed to write {}", path))?;
+    println!("Created {}", path);
+    Ok(())
+}
+
+/// Declares the module `{prefix}{number}` among the other modules with that prefix,
+/// keeping them in numeric order, or at the end of the file if there are none
+fn declare(path: &str, prefix: &str, number: usize) -> Result<()> {
+    edit(path, |contents| {
+        let number_of = |line: &str| -> Option<usize> {
+            let name = line.strip_prefix("pub mod ")?.strip_suffix(';')?;
+            name.strip_prefix(prefix)?.parse().ok()
+        };
+        let mut lines: Vec<_> = contents.lines().collect();
+        let declared: Vec<_> = (0..lines.len())
+            .filter_map(|i| Some((i, number_of(lines[i])?)))
+            .collect();
+        let position = declared
+            .iter()
+            .find(|&&(_, other)| other > number)
+            .map(|&(i, _)| i)
+            .or_else(|| declared.last().map(|&(i, _)| i + 1))
+            .unwrap_or(lines.len());
+        let declaration = format!("pub mod {}{};", prefix, number);
+        lines.insert(position, &declaration);
+        Ok(lines.join("\n") + "\n")
+    })
+}
+
+fn edit(path: &str, change: impl FnOnce(&str) -> Result<String>) -> Result<()> {
+    let contents = fs::read_to_string(path).with_context(|| format!("Failed to read {}", path))?;
+    let changed = change(&contents).with_context(|| format!("Failed to update {}", path))?;
+    fs::write(path, changed).with_context(|| format!("Failed to write {}", path))?;
+    println!("Updated {}", path);
+    Ok(())
+}
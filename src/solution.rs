@@ -1,10 +1,215 @@
-use anyhow::Result;
-use std::fmt::Display;
+use anyhow::{Context, Error, Result, bail};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+use std::str::FromStr;
 
 pub trait Solution: Default {
-    type Part1Output: Display;
-    fn part1(&self, input: &str) -> Result<Self::Part1Output>;
+    /// The examples from the puzzle description
+    const EXAMPLES: &'static [Example];
 
-    type Part2Output: Display;
-    fn part2(&self, input: &str) -> Result<Self::Part2Output>;
+    /// Whether the puzzle has a second part, which the last day of a year doesn't
+    const HAS_PART2: bool = true;
+
+    /// The constants this day can be tuned with, as `(name, value)` pairs
+    fn params(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    /// Overrides one of the constants listed by `params`
+    fn set_param(&mut self, name: &str, _value: &str) -> Result<()> {
+        bail!("unknown parameter '{}'", name)
+    }
+
+    /// The puzzle input after parsing, shared by both parts
+    type Input;
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+
+    type Part1Output: IntoAnswer;
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1Output>;
+
+    type Part2Output: IntoAnswer;
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2Output>;
+}
+
+/// The answer to one part: the value that is checked and submitted, which may be several lines
+/// of ASCII art, and an explanation of how it was found that is only ever shown
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct Answer {
+    pub value: String,
+    pub explanation: Option<String>,
+}
+
+impl Answer {
+    pub fn new(value: impl Display) -> Self {
+        Self {
+            value: value.to_string(),
+            explanation: None,
+        }
+    }
+
+    pub fn explained(value: impl Display, explanation: impl Display) -> Self {
+        Self {
+            value: value.to_string(),
+            explanation: Some(explanation.to_string()),
+        }
+    }
+
+    /// Draws the lit cells of a grid with `#` and the rest with `.`, one line per row
+    pub fn drawing<R: IntoIterator<Item = bool>>(rows: impl IntoIterator<Item = R>) -> Self {
+        let lines: Vec<String> = rows
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|lit| if lit { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        Self::new(lines.join("\n"))
+    }
+
+    pub fn is_multiline(&self) -> bool {
+        self.value.contains('\n')
+    }
+}
+
+/// What a part can return: any value that can be displayed, or an [`Answer`] for the rest
+pub trait IntoAnswer {
+    fn into_answer(self) -> Answer;
+}
+
+impl<T: Display> IntoAnswer for T {
+    fn into_answer(self) -> Answer {
+        Answer::new(self)
+    }
+}
+
+impl IntoAnswer for Answer {
+    fn into_answer(self) -> Answer {
+        self
+    }
+}
+
+/// An example input from the puzzle description and the answers it should give
+pub struct Example {
+    pub input: &'static str,
+    /// Parameters that differ from the real input, such as a smaller number of steps
+    pub params: &'static [(&'static str, &'static str)],
+    /// `None` when the puzzle gives no answer for the part on this example
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+impl Example {
+    pub fn params(&self) -> Vec<Param> {
+        self.params
+            .iter()
+            .map(|&(name, value)| Param {
+                name: name.to_string(),
+                value: value.to_string(),
+            })
+            .collect()
+    }
+
+    pub fn answer(&self, part: Part) -> Option<&'static str> {
+        match part {
+            Part::One => self.part1,
+            Part::Two => self.part2,
+        }
+    }
+}
+
+/// Parses the value of a parameter given on the command line
+pub fn parse_param<T>(name: &str, value: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    value
+        .parse()
+        .with_context(|| format!("invalid value '{}' for {}", value, name))
+}
+
+/// A `name=value` override for one of a solution's parameters
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Param {
+    pub name: String,
+    pub value: String,
+}
+
+impl FromStr for Param {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (name, value) = s
+            .split_once('=')
+            .with_context(|| format!("expected name=value, got '{}'", s))?;
+        Ok(Param {
+            name: name.trim().to_string(),
+            value: value.trim().to_string(),
+        })
+    }
+}
+
+impl Display for Param {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}={}", self.name, self.value)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Part {
+    One = 1,
+    Two = 2,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// Whether this part is included when `selected` is the user's choice of part
+    pub fn is_selected(self, selected: Option<Part>) -> bool {
+        selected.is_none_or(|selected| selected == self)
+    }
+}
+
+impl FromStr for Part {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "1" => Part::One,
+            "2" => Part::Two,
+            _ => bail!("part must be 1 or 2, got '{}'", s),
+        })
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drawings_have_a_line_per_row() {
+        let answer = Answer::drawing([[true, false, true], [false, true, false]]);
+        assert_eq!(answer.value, "#.#\n.#.");
+        assert_eq!(answer.explanation, None);
+        assert!(answer.is_multiline());
+    }
+
+    #[test]
+    fn displayed_values_are_their_own_answer() {
+        let answer = 42.into_answer();
+        assert_eq!(answer, Answer::new("42"));
+        assert!(!answer.is_multiline());
+        let explained = Answer::explained(42, "6 times 7");
+        assert_eq!(explained.clone().into_answer(), explained);
+    }
 }
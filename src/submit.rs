@@ -0,0 +1,222 @@
+use crate::cli::SubmitArgs;
+use crate::client::{Client, Verdict};
+use crate::input::Input;
+use anyhow::{Context, Result, bail, ensure};
+use aoc2025::registry;
+use aoc2025::solution::Part;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Every answer submitted so far, kept so that answers known to be wrong are never sent again
+#[derive(Serialize, Deserialize, Default)]
+struct History {
+    #[serde(default, rename = "attempt")]
+    attempts: Vec<Attempt>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Attempt {
+    year: u16,
+    day: usize,
+    part: u8,
+    answer: String,
+    verdict: Verdict,
+    /// Unix time of the submission
+    submitted_at: u64,
+    /// Unix time before which the site won't check another answer for the day
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    retry_at: Option<u64>,
+}
+
+impl History {
+    fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read history {}", path.display()))?;
+        toml::from_str(&contents)
+            .with_context(|| format!("Failed to parse history {}", path.display()))
+    }
+
+    fn save(&self, path: &Path) -> Result<()> {
+        let contents = toml::to_string(self)?;
+        fs::write(path, contents)
+            .with_context(|| format!("Failed to write history {}", path.display()))
+    }
+
+    /// Refuses an answer that the earlier attempts show to be pointless or too soon
+    fn check(&self, year: u16, day: usize, part: Part, answer: &str, now: u64) -> Result<()> {
+        let same_day = self
+            .attempts
+            .iter()
+            .filter(|attempt| attempt.year == year && attempt.day == day);
+        let same_part = same_day
+            .clone()
+            .filter(|attempt| attempt.part == part as u8);
+
+        if let Some(correct) = same_part
+            .clone()
+            .find(|attempt| attempt.verdict == Verdict::Correct)
+        {
+            bail!(
+                "Part {} is already solved, the answer was {}",
+                part,
+                correct.answer
+            );
+        }
+        // the same answer may also have been sent too soon, so any wrong verdict counts
+        if let Some(attempt) = same_part
+            .clone()
+            .find(|attempt| attempt.answer == answer && attempt.verdict.is_wrong())
+        {
+            bail!(
+                "{} was already submitted and was {}",
+                answer,
+                attempt.verdict
+            );
+        }
+        if let Ok(number) = answer.parse::<i128>() {
+            let bound = |verdict| {
+                same_part
+                    .clone()
+                    .filter(move |attempt| attempt.verdict == verdict)
+                    .filter_map(|attempt| attempt.answer.parse::<i128>().ok())
+            };
+            if let Some(high) = bound(Verdict::TooHigh).min() {
+                ensure!(
+                    number < high,
+                    "{} is too high, {} already was",
+                    number,
+                    high
+                );
+            }
+            if let Some(low) = bound(Verdict::TooLow).max() {
+                ensure!(number > low, "{} is too low, {} already was", number, low);
+            }
+        }
+        if let Some(retry_at) = same_day.filter_map(|attempt| attempt.retry_at).max() {
+            ensure!(
+                now >= retry_at,
+                "The site won't check another answer for {:?}",
+                Duration::from_secs(retry_at - now)
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Solves a part and submits the answer, returning whether it was right
+pub fn submit(args: &SubmitArgs) -> Result<bool> {
+    let year = crate::resolve_year(args.year)?;
+    let puzzle = registry::puzzle(year, args.day)?;
+    ensure!(
+        puzzle.solver.parts().any(|part| part == args.part),
+        "Day {} has no part {}",
+        args.day,
+        args.part
+    );
+    let input = Input::from_default_path(year, args.day)?;
+    let result = puzzle
+        .solver
+        .solve(&input.contents, Some(args.part), &args.params)
+        .remove(0);
+    let answer = result.answer.context("Failed to solve the part")?.value;
+    ensure!(
+        !answer.contains('\n'),
+        "The answer is a drawing, so read it and submit it on the puzzle page:\n{}",
+        answer
+    );
+
+    let mut history = History::load(&args.history)?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    history.check(year, args.day, args.part, &answer, now)?;
+
+    println!(
+        "Submitting {} for day {} part {}",
+        answer, args.day, args.part
+    );
+    let response = Client::new(&args.site)?.submit(year, args.day, args.part, &answer)?;
+    history.attempts.push(Attempt {
+        year,
+        day: args.day,
+        part: args.part as u8,
+        answer,
+        verdict: response.verdict,
+        submitted_at: now,
+        retry_at: response.wait.map(|wait| now + wait.as_secs()),
+    });
+    history.save(&args.history)?;
+
+    println!("{}: {}", response.verdict, response.message);
+    Ok(response.verdict == Verdict::Correct)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attempt(part: u8, answer: &str, verdict: Verdict, retry_at: Option<u64>) -> Attempt {
+        Attempt {
+            year: 2025,
+            day: 3,
+            part,
+            answer: answer.to_string(),
+            verdict,
+            submitted_at: 0,
+            retry_at,
+        }
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let history = History {
+            attempts: vec![
+                attempt(1, "100", Verdict::TooHigh, None),
+                attempt(1, "10", Verdict::TooLow, None),
+                attempt(1, "50", Verdict::Wrong, None),
+            ],
+        };
+        assert!(history.check(2025, 3, Part::One, "50", 0).is_err());
+        assert!(history.check(2025, 3, Part::One, "100", 0).is_err());
+        assert!(history.check(2025, 3, Part::One, "150", 0).is_err());
+        assert!(history.check(2025, 3, Part::One, "5", 0).is_err());
+        assert!(history.check(2025, 3, Part::One, "51", 0).is_ok());
+        // the bounds only apply to the same part
+        assert!(history.check(2025, 3, Part::Two, "150", 0).is_ok());
+    }
+
+    #[test]
+    fn refuses_answers_that_were_wrong_after_being_sent_too_soon() {
+        let history = History {
+            attempts: vec![
+                attempt(1, "50", Verdict::TooSoon, None),
+                attempt(1, "50", Verdict::WrongLevel, None),
+                attempt(1, "50", Verdict::Wrong, None),
+            ],
+        };
+        assert!(history.check(2025, 3, Part::One, "50", 0).is_err());
+        assert!(history.check(2025, 3, Part::One, "51", 0).is_ok());
+    }
+
+    #[test]
+    fn waits_out_the_rate_limit() {
+        let history = History {
+            attempts: vec![attempt(1, "50", Verdict::Wrong, Some(60))],
+        };
+        assert!(history.check(2025, 3, Part::Two, "7", 30).is_err());
+        assert!(history.check(2025, 3, Part::Two, "7", 60).is_ok());
+        assert!(history.check(2025, 4, Part::One, "7", 30).is_ok());
+    }
+
+    #[test]
+    fn refuses_solved_parts() {
+        let history = History {
+            attempts: vec![attempt(2, "42", Verdict::Correct, None)],
+        };
+        assert!(history.check(2025, 3, Part::Two, "43", 0).is_err());
+        assert!(history.check(2025, 3, Part::One, "43", 0).is_ok());
+    }
+}
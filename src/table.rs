@@ -0,0 +1,79 @@
+use std::fmt::{self, Display};
+
+/// A plain text table with left-aligned columns, whose cells may span several lines
+pub struct Table {
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new<S: ToString>(header: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            rows: vec![header.into_iter().map(|cell| cell.to_string()).collect()],
+        }
+    }
+
+    pub fn push<S: ToString>(&mut self, row: impl IntoIterator<Item = S>) {
+        self.rows
+            .push(row.into_iter().map(|cell| cell.to_string()).collect());
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let num_columns = self.rows.iter().map(Vec::len).max().unwrap_or_default();
+        let widths: Vec<_> = (0..num_columns)
+            .map(|i| {
+                self.rows
+                    .iter()
+                    .filter_map(|row| row.get(i))
+                    .flat_map(|cell| cell.lines())
+                    .map(|line| line.chars().count())
+                    .max()
+                    .unwrap_or_default()
+            })
+            .collect();
+        for row in &self.rows {
+            let height = row
+                .iter()
+                .map(|cell| cell.lines().count())
+                .max()
+                .unwrap_or_default()
+                .max(1);
+            for i in 0..height {
+                let line = row
+                    .iter()
+                    .zip(&widths)
+                    .map(|(cell, &width)| {
+                        let line = cell.lines().nth(i).unwrap_or_default();
+                        format!("{:<width$}", line, width = width)
+                    })
+                    .collect::<Vec<_>>()
+                    .join("  ");
+                writeln!(f, "{}", line.trim_end())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cells_spanning_several_lines_make_the_row_taller() {
+        let mut table = Table::new(["Part", "Answer", "Result"]);
+        table.push(["1", "#..#\n####", "pass"]);
+        table.push(["2", "7", "FAIL"]);
+        let lines: Vec<_> = table.to_string().lines().map(str::to_string).collect();
+        assert_eq!(
+            lines,
+            [
+                "Part  Answer  Result",
+                "1     #..#    pass",
+                "      ####",
+                "2     7       FAIL",
+            ]
+        );
+    }
+}
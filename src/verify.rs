@@ -0,0 +1,99 @@
+use crate::answers::Answers;
+use crate::cli::VerifyArgs;
+use crate::input::Input;
+use crate::table::Table;
+use crate::{check_params, select, solve_input};
+use anyhow::{Result, bail};
+use aoc2025::solution::Part;
+use itertools::Itertools;
+
+/// Runs every solver that has known answers and prints a pass/fail table,
+/// returning whether all of the answers matched
+pub fn verify(args: &VerifyArgs) -> Result<bool> {
+    let answers = Answers::load(&args.answers)?;
+    let mut table = Table::new(["Day", "Part", "Input", "Expected", "Actual", "Result"]);
+    let mut passed = 0;
+    let mut failed = 0;
+    let mut unverified = Vec::new();
+
+    let puzzles = select(&args.selection)?;
+    check_params(&puzzles, &args.params)?;
+    for puzzle in puzzles {
+        let day = puzzle.day;
+        let mut has_answers = false;
+        for (path, expected) in answers.for_day(puzzle.year, day) {
+            if let Some(part) = Part::ALL.into_iter().find(|&part| {
+                expected.get(part).is_some() && !puzzle.solver.parts().any(|p| p == part)
+            }) {
+                bail!(
+                    "{} has an answer to part {} for {}, but day {} has no part {}",
+                    args.answers.display(),
+                    part,
+                    path.display(),
+                    day,
+                    part
+                );
+            }
+            let parts: Vec<_> = puzzle
+                .solver
+                .parts()
+                .filter(|&part| part.is_selected(args.selection.part))
+                .filter_map(|part| Some((part, expected.get(part)?)))
+                .collect();
+            if parts.is_empty() {
+                continue;
+            }
+            has_answers = true;
+
+            // parse the input once for the parts that have answers, which are solved in order
+            let selected = match parts[..] {
+                [(part, _)] => Some(part),
+                _ => None,
+            };
+            let actuals: Vec<_> = match Input::from_file(path) {
+                Ok(input) => solve_input(
+                    puzzle,
+                    selected,
+                    &input.contents,
+                    &args.params,
+                    &args.limits,
+                )
+                .into_iter()
+                .map(|result| match result.answer {
+                    Ok(answer) => answer.value,
+                    Err(err) => format!("error: {:#}", err),
+                })
+                .collect(),
+                Err(err) => parts.iter().map(|_| format!("error: {:#}", err)).collect(),
+            };
+            for ((part, expected), actual) in parts.into_iter().zip(actuals) {
+                let status = if actual == expected {
+                    passed += 1;
+                    "pass"
+                } else {
+                    failed += 1;
+                    "FAIL"
+                };
+                table.push([
+                    day.to_string(),
+                    part.to_string(),
+                    path.display().to_string(),
+                    expected.to_string(),
+                    actual,
+                    status.to_string(),
+                ]);
+            }
+        }
+        if !has_answers {
+            unverified.push(day);
+        }
+    }
+
+    print!("{}", table);
+    println!();
+    println!("{} passed, {} failed", passed, failed);
+    if !unverified.is_empty() {
+        println!("No known answers for day {}", unverified.iter().join(", "));
+    }
+    Ok(failed == 0)
+}
@@ -0,0 +1,301 @@
+use crate::answers::Answers;
+use crate::cli::WatchArgs;
+use crate::input::{self, Input};
+use crate::report;
+use crate::table::Table;
+use anyhow::{Context, Result, ensure};
+use aoc2025::registry::{self, Puzzle};
+use aoc2025::solution::Part;
+use itertools::Itertools;
+use notify::event::ModifyKind;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::collections::BTreeMap;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+/// How long to wait for more changes before running, as editors often write a file in several steps
+const SETTLE_TIME: Duration = Duration::from_millis(200);
+
+/// Carries the answers across a restart into the rebuilt binary, so that changes can still be shown
+const PREVIOUS_ANSWERS_VAR: &str = "AOC_WATCH_PREVIOUS";
+
+/// The latest answer, or error, for each input and part
+type Results = BTreeMap<(String, u8), String>;
+
+/// What has to happen for a changed file to take effect
+#[derive(Debug, PartialEq, Eq)]
+enum Change {
+    /// The real input is read at run time, so the day only has to run again
+    Input,
+    /// The source and the examples are compiled in, so the binary has to be rebuilt
+    Build,
+}
+
+/// An input to run together with the answers it is known to have, by part
+struct Case {
+    input: Input,
+    expected: [Option<String>; 2],
+}
+
+/// Runs a day on its examples and its input, then again whenever any of them or the source changes
+pub fn watch(args: &WatchArgs) -> Result<()> {
+    ensure!(
+        Path::new("Cargo.toml").exists() && Path::new("src/registry.rs").exists(),
+        "`watch` must be run from the root of the repository"
+    );
+    let year = crate::resolve_year(args.year)?;
+    let puzzle = registry::puzzle(year, args.day)?;
+    crate::check_params(&[puzzle], &args.params)?;
+    // rebuilding replaces the binary, after which its path can no longer be looked up
+    let exe = env::current_exe()?;
+    let root = env::current_dir()?;
+
+    let (sender, events) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender)?;
+    watcher.watch(&root.join("src"), RecursiveMode::Recursive)?;
+    for dir in [format!("examples/{}", year), format!("inputs/{}", year)] {
+        if Path::new(&dir).is_dir() {
+            watcher.watch(&root.join(dir), RecursiveMode::NonRecursive)?;
+        }
+    }
+
+    let mut previous = match env::var(PREVIOUS_ANSWERS_VAR) {
+        Ok(json) => serde_json::from_str::<Vec<_>>(&json)?.into_iter().collect(),
+        Err(_) => Results::new(),
+    };
+    let mut reason = if previous.is_empty() {
+        "first run".to_string()
+    } else {
+        "rebuilt".to_string()
+    };
+    loop {
+        previous = run(puzzle, args, &reason, &previous);
+        println!("Watching for changes, press Ctrl-C to stop");
+        let changes = wait_for_changes(&events, &root, puzzle)?;
+        reason = format!(
+            "{} changed",
+            changes.iter().map(|(path, _)| path.display()).join(", ")
+        );
+        if changes.iter().any(|(_, change)| *change == Change::Build) {
+            println!("{}, rebuilding...", reason);
+            if rebuild()? {
+                restart(&exe, &previous)?;
+            }
+            println!("The build failed, waiting for another change");
+            println!();
+        }
+    }
+}
+
+/// Solves every case once, printing the answers and any that changed since the previous run
+fn run(puzzle: &Puzzle, args: &WatchArgs, reason: &str, previous: &Results) -> Results {
+    println!("--- Day {}: {} ({}) ---", puzzle.day, puzzle.title, reason);
+    let mut table = Table::new(["Input", "Part", "Answer", "Expected", "Result", "Time"]);
+    let mut results = Results::new();
+    let mut changes = Vec::new();
+    let mut underlines = Vec::new();
+    for case in cases(puzzle, args) {
+        let case = match case {
+            Ok(case) => case,
+            Err(err) => {
+                println!("{:#}", err);
+                continue;
+            }
+        };
+        let params = [&case.input.params[..], &args.params[..]].concat();
+        for result in puzzle.solver.solve(&case.input.contents, None, &params) {
+            let answer = match &result.answer {
+                Ok(answer) => answer.value.clone(),
+                Err(err) => format!("error: {:#}", err),
+            };
+            let expected = case.expected[result.part as usize - 1].as_deref();
+            let status = match expected {
+                Some(expected) if expected == answer => "pass",
+                Some(_) => "FAIL",
+                None => "",
+            };
+            if let Some(underline) = report::underline(&result.answer) {
+                let underline = format!("{}:\n{}", case.input.name, underline);
+                if !underlines.contains(&underline) {
+                    underlines.push(underline);
+                }
+            }
+            let key = (case.input.name.clone(), result.part as u8);
+            if let Some(old) = previous.get(&key).filter(|&old| *old != answer) {
+                changes.push(format!(
+                    "  {} part {}: {} -> {}",
+                    case.input.name, result.part, old, answer
+                ));
+            }
+            table.push([
+                case.input.name.clone(),
+                result.part.to_string(),
+                answer.clone(),
+                expected.unwrap_or_default().to_string(),
+                status.to_string(),
+                format!("{:.2?}", result.total_elapsed()),
+            ]);
+            results.insert(key, answer);
+        }
+    }
+    print!("{}", table);
+    underlines
+        .iter()
+        .for_each(|underline| println!("{}", underline));
+    if !changes.is_empty() {
+        println!("Changed since the last run:");
+        changes.iter().for_each(|change| println!("{}", change));
+    } else if !previous.is_empty() {
+        println!("No answers changed");
+    }
+    println!();
+    results
+}
+
+/// The day's examples with the answers from the puzzle description,
+/// followed by its real input with any answers from the answers file
+fn cases(puzzle: &Puzzle, args: &WatchArgs) -> Vec<Result<Case>> {
+    let mut cases: Vec<_> = match input::examples(puzzle) {
+        Ok(inputs) => inputs
+            .into_iter()
+            .zip(puzzle.solver.examples())
+            .map(|(input, example)| {
+                let expected = Part::ALL.map(|part| example.answer(part).map(str::to_string));
+                Ok(Case { input, expected })
+            })
+            .collect(),
+        Err(err) => vec![Err(err)],
+    };
+
+    let path = input::default_path(puzzle.year, puzzle.day);
+    // the answers file may be edited while watching, so it is read every time
+    let answers = if args.answers.exists() {
+        Answers::load(&args.answers).map(Some)
+    } else {
+        Ok(None)
+    };
+    cases.push(answers.and_then(|answers| {
+        let known = answers
+            .iter()
+            .flat_map(|answers| answers.for_day(puzzle.year, puzzle.day))
+            .find(|(known, _)| *known == path)
+            .map(|(_, known)| known);
+        let expected = Part::ALL.map(|part| Some(known?.get(part)?.to_string()));
+        Ok(Case {
+            input: Input::from_default_path(puzzle.year, puzzle.day)?,
+            expected,
+        })
+    }));
+    cases
+}
+
+/// Blocks until one of the day's files changes, returning the changed files relative to `root`
+fn wait_for_changes(
+    events: &Receiver<notify::Result<Event>>,
+    root: &Path,
+    puzzle: &Puzzle,
+) -> Result<Vec<(PathBuf, Change)>> {
+    let mut changes: Vec<(PathBuf, Change)> = Vec::new();
+    loop {
+        let event = if changes.is_empty() {
+            events.recv().context("Stopped receiving file changes")?
+        } else {
+            match events.recv_timeout(SETTLE_TIME) {
+                Ok(event) => event,
+                Err(RecvTimeoutError::Timeout) => return Ok(changes),
+                Err(RecvTimeoutError::Disconnected) => {
+                    return Err(RecvTimeoutError::Disconnected)
+                        .context("Stopped receiving file changes");
+                }
+            }
+        };
+        let event = event?;
+        if !matches!(
+            event.kind,
+            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+        ) || matches!(event.kind, EventKind::Modify(ModifyKind::Metadata(_)))
+        {
+            continue;
+        }
+        for path in event.paths {
+            let path = path.strip_prefix(root).unwrap_or(&path).to_path_buf();
+            if let Some(change) = classify(&path, puzzle.year, puzzle.day)
+                && !changes.iter().any(|(changed, _)| *changed == path)
+            {
+                changes.push((path, change));
+            }
+        }
+    }
+}
+
+/// Whether a changed file affects the day, and how
+fn classify(path: &Path, year: u16, day: usize) -> Option<Change> {
+    let name = path.file_name()?.to_str()?;
+    if path.starts_with("src") {
+        // any module may be shared with the day, so every source file counts
+        return (path.extension()? == "rs").then_some(Change::Build);
+    }
+    if path.parent()? == Path::new(&format!("examples/{}", year)) {
+        // a day with several examples has files like day11-2.txt
+        let rest = name.strip_prefix(&format!("day{}", day))?;
+        return (rest.starts_with('.') || rest.starts_with('-')).then_some(Change::Build);
+    }
+    (path == input::default_path(year, day)).then_some(Change::Input)
+}
+
+/// Rebuilds the binary with the profile and features it was built with, returning whether the build succeeded
+fn rebuild() -> Result<bool> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut command = Command::new(cargo);
+    command.args(["build", "--quiet"]);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    if cfg!(feature = "count-allocations") {
+        command.args(["--features", "count-allocations"]);
+    }
+    let status = command.status().context("Failed to run cargo")?;
+    Ok(status.success())
+}
+
+/// Replaces this process with the rebuilt binary, run with the same arguments
+fn restart(exe: &Path, previous: &Results) -> Result<()> {
+    let previous: Vec<_> = previous.iter().collect();
+    let mut command = Command::new(exe);
+    command
+        .args(env::args_os().skip(1))
+        .env(PREVIOUS_ANSWERS_VAR, serde_json::to_string(&previous)?);
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        Err(command.exec()).context("Failed to restart the rebuilt binary")
+    }
+    #[cfg(not(unix))]
+    {
+        let status = command
+            .status()
+            .context("Failed to start the rebuilt binary")?;
+        std::process::exit(status.code().unwrap_or(1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_changed_files() {
+        let classify = |path: &str| classify(Path::new(path), 2025, 1);
+        assert_eq!(classify("inputs/2025/day1.txt"), Some(Change::Input));
+        assert_eq!(classify("inputs/2025/day11.txt"), None);
+        assert_eq!(classify("inputs/2024/day1.txt"), None);
+        assert_eq!(classify("examples/2025/day1.txt"), Some(Change::Build));
+        assert_eq!(classify("examples/2025/day1-2.txt"), Some(Change::Build));
+        assert_eq!(classify("examples/2025/day11.txt"), None);
+        assert_eq!(classify("src/y2025/day3.rs"), Some(Change::Build));
+        assert_eq!(classify("src/y2025/.day1.rs.swp"), None);
+    }
+}
@@ -0,0 +1,318 @@
+pub struct Day1 {
+    start: usize,
+    size: usize,
+}
+
+impl Default for Day1 {
+    fn default() -> Self {
+        Self {
+            start: 50,
+            size: 100,
+        }
+    }
+}
+
+use crate::generate::{Generator, parse_knob};
+use crate::parse::{ParseContext, ParseError, Parser};
+use crate::registry::Puzzle;
+use crate::solution::{Example, Solution, parse_param};
+use anyhow::{Context, Result, bail, ensure};
+use rand::RngExt;
+use rand::rngs::StdRng;
+
+pub enum Dir {
+    Left,
+    Right,
+}
+
+/// A combination lock dial numbered from 0 to `size - 1`
+pub struct Dial {
+    pub position: usize,
+    pub size: usize,
+}
+
+impl Dial {
+    pub fn new(position: usize, size: usize) -> Self {
+        Self { position, size }
+    }
+
+    pub fn turn(&self, dir: &Dir, amount: usize) -> Self {
+        // wrap without adding past the size, which could overflow for a dial as big as a usize
+        let step = amount % self.size;
+        let next = match *dir {
+            Dir::Left => match self.position.checked_sub(step) {
+                Some(next) => next,
+                None => self.size - (step - self.position),
+            },
+            Dir::Right => {
+                let to_zero = self.size - self.position;
+                if step < to_zero {
+                    self.position + step
+                } else {
+                    step - to_zero
+                }
+            }
+        };
+        Self::new(next, self.size)
+    }
+
+    /// Turns the dial, also counting how many times it points at 0 on the way
+    pub fn turn_and_count(&self, dir: &Dir, amount: usize) -> (Self, usize) {
+        let next = self.turn(dir, amount);
+        let full_turns = amount / self.size;
+        // the rest of the turn is less than a full turn, so it can only reach 0 if it didn't
+        // start there
+        let to_zero = match dir {
+            Dir::Left => self.position,
+            Dir::Right => (self.size - self.position) % self.size,
+        };
+        let zero_crossings = usize::from(to_zero > 0 && amount % self.size >= to_zero);
+        let count = full_turns + zero_crossings;
+
+        (next, count)
+    }
+}
+
+pub fn parse_input(input: &str) -> impl Iterator<Item = Result<(Dir, usize), ParseError>> {
+    let parser = Parser::new(input);
+    input.lines().map(move |line| {
+        let (direction, amount) =
+            line.split_at_checked(1)
+                .at(input, line, "expected a direction and a distance")?;
+        let amount = parser.number(amount)?;
+        let direction = match direction {
+            "L" => Dir::Left,
+            "R" => Dir::Right,
+            _ => return Err(parser.error(direction, "invalid direction")),
+        };
+        Ok((direction, amount))
+    })
+}
+
+impl Day1 {
+    fn dial(&self) -> Result<Dial> {
+        ensure!(
+            self.start < self.size,
+            "the dial starts at {} but only goes up to {}",
+            self.start,
+            self.size - 1
+        );
+        Ok(Dial::new(self.start, self.size))
+    }
+}
+
+inventory::submit! {
+    Puzzle::new::<Day1>(2025, 1, "Secret Entrance")
+        .with_generator::<Day1Generator>()
+        .with_reference::<Day1Naive>()
+}
+
+impl Solution for Day1 {
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../../examples/2025/day1.txt"),
+        params: &[],
+        part1: Some("3"),
+        part2: Some("6"),
+    }];
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("start", self.start.to_string()),
+            ("size", self.size.to_string()),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<()> {
+        match name {
+            "start" => self.start = parse_param(name, value)?,
+            "size" => {
+                self.size = parse_param(name, value)?;
+                ensure!(self.size > 0, "the dial needs at least one number");
+            }
+            _ => bail!("unknown parameter '{}'", name),
+        }
+        Ok(())
+    }
+
+    type Input = Vec<(Dir, usize)>;
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input).collect::<Result<_, _>>()?)
+    }
+
+    type Part1Output = usize;
+    fn part1(&self, rotations: &Self::Input) -> Result<Self::Part1Output> {
+        let count = rotations
+            .iter()
+            .scan(self.dial()?, |dial, (dir, amount)| {
+                *dial = dial.turn(dir, *amount);
+                Some(dial.position)
+            })
+            .filter(|&num| num == 0)
+            .count();
+        Ok(count)
+    }
+
+    type Part2Output = usize;
+    fn part2(&self, rotations: &Self::Input) -> Result<Self::Part2Output> {
+        let mut dial = self.dial()?;
+        let mut count = 0usize;
+        for (dir, amount) in rotations {
+            let (next, zeros) = dial.turn_and_count(dir, *amount);
+            count = count
+                .checked_add(zeros)
+                .context("the dial points at 0 too often to count")?;
+            dial = next;
+        }
+        Ok(count)
+    }
+}
+
+/// Random rotations of the dial
+pub struct Day1Generator {
+    rotations: usize,
+    max_distance: usize,
+}
+
+impl Default for Day1Generator {
+    fn default() -> Self {
+        Self {
+            rotations: 4000,
+            max_distance: 999,
+        }
+    }
+}
+
+impl Generator for Day1Generator {
+    const SMALL: &'static [(&'static str, &'static str)] =
+        &[("rotations", "50"), ("max_distance", "300")];
+
+    fn knobs(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("rotations", self.rotations.to_string()),
+            ("max_distance", self.max_distance.to_string()),
+        ]
+    }
+
+    fn set_knob(&mut self, name: &str, value: &str) -> Result<()> {
+        match name {
+            "rotations" => self.rotations = parse_knob(name, value, 1..)?,
+            "max_distance" => self.max_distance = parse_knob(name, value, 1..=usize::MAX / 2)?,
+            _ => bail!("unknown knob '{}'", name),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut StdRng) -> String {
+        (0..self.rotations)
+            .map(|_| {
+                let direction = if rng.random_bool(0.5) { 'L' } else { 'R' };
+                let distance = rng.random_range(1..=self.max_distance);
+                format!("{}{}\n", direction, distance)
+            })
+            .collect()
+    }
+}
+
+/// Turns the dial one click at a time, as a reference for the arithmetic in [`Dial`]
+#[derive(Default)]
+pub struct Day1Naive(Day1);
+
+impl Day1Naive {
+    /// The number the dial points at after each click of every rotation, grouped by rotation
+    fn clicks(&self, rotations: &[(Dir, usize)]) -> Result<Vec<Vec<usize>>> {
+        let Dial { mut position, size } = self.0.dial()?;
+        let clicks = rotations
+            .iter()
+            .map(|(dir, amount)| {
+                (0..*amount)
+                    .map(|_| {
+                        position = match dir {
+                            Dir::Left => (position + size - 1) % size,
+                            Dir::Right => (position + 1) % size,
+                        };
+                        position
+                    })
+                    .collect()
+            })
+            .collect();
+        Ok(clicks)
+    }
+}
+
+impl Solution for Day1Naive {
+    const EXAMPLES: &'static [Example] = Day1::EXAMPLES;
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        self.0.params()
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<()> {
+        self.0.set_param(name, value)
+    }
+
+    type Input = Vec<(Dir, usize)>;
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        self.0.parse(input)
+    }
+
+    type Part1Output = usize;
+    fn part1(&self, rotations: &Self::Input) -> Result<Self::Part1Output> {
+        let clicks = self.clicks(rotations)?;
+        let start = self.0.start;
+        let ends = clicks
+            .iter()
+            .scan(start, |position, clicks| {
+                *position = clicks.last().copied().unwrap_or(*position);
+                Some(*position)
+            })
+            .filter(|&position| position == 0);
+        Ok(ends.count())
+    }
+
+    type Part2Output = usize;
+    fn part2(&self, rotations: &Self::Input) -> Result<Self::Part2Output> {
+        let clicks = self.clicks(rotations)?;
+        Ok(clicks
+            .iter()
+            .flatten()
+            .filter(|&&position| position == 0)
+            .count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::check_examples;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day1>();
+        check_examples::<Day1Naive>();
+    }
+
+    #[test]
+    fn full_turns_pass_zero_each_time() {
+        let (dial, count) = Dial::new(50, 100).turn_and_count(&Dir::Right, 1000);
+        assert_eq!(dial.position, 50);
+        assert_eq!(count, 10);
+    }
+
+    #[test]
+    fn full_turns_from_zero_end_on_zero_once() {
+        for dir in [Dir::Left, Dir::Right] {
+            let (dial, count) = Dial::new(0, 100).turn_and_count(&dir, 200);
+            assert_eq!(dial.position, 0);
+            assert_eq!(count, 2);
+        }
+    }
+
+    #[test]
+    fn the_biggest_dial_does_not_overflow() {
+        let size = usize::MAX;
+        let dial = Dial::new(size - 2, size);
+        assert_eq!(dial.turn(&Dir::Right, 5).position, 3);
+        assert_eq!(dial.turn(&Dir::Left, size - 1).position, size - 1);
+        assert_eq!(Dial::new(1, size).turn(&Dir::Left, 3).position, size - 2);
+    }
+}
@@ -0,0 +1,530 @@
+use crate::generate::{Generator, parse_knob};
+use crate::parse::{ParseContext, ParseError, Parser};
+use crate::registry::Puzzle;
+use crate::solution::{Example, Solution};
+use anyhow::{Context, Result, bail, ensure};
+use itertools::Itertools;
+use rand::RngExt;
+use rand::rngs::StdRng;
+use rand::seq::index;
+use std::collections::hash_map::Entry::Vacant;
+use std::collections::{HashMap, VecDeque};
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct BitSequence(u16);
+
+impl FromStr for BitSequence {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let lights = Parser::new(s).cells(
+            s,
+            |c| match c {
+                '#' => Some(true),
+                '.' => Some(false),
+                _ => None,
+            },
+            "expected '#' or '.'",
+        )?;
+        Ok(lights.into_iter().collect())
+    }
+}
+
+impl FromIterator<bool> for BitSequence {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let value = iter
+            .into_iter()
+            .take(16)
+            .enumerate()
+            .fold(0u16, |acc, (i, b)| acc | ((b as u16) << (15 - i)));
+        Self(value)
+    }
+}
+
+impl BitSequence {
+    pub fn toggle_bits(&self, indices: &[usize]) -> Self {
+        let Self(inner) = self;
+        let toggled = indices.iter().fold(*inner, |acc, &i| acc ^ (1 << (15 - i)));
+        Self(toggled)
+    }
+}
+
+pub struct Machine {
+    /// The lights that need to be turned on
+    pub lights: BitSequence,
+    /// The counters each button increments
+    pub buttons: Vec<Vec<usize>>,
+    pub joltages: Vec<usize>,
+}
+
+pub fn parse_line(line: &str) -> Result<Machine, ParseError> {
+    let (lights, buttons, joltages) = line
+        .split_once(']')
+        .and_then(|(lights, rest)| {
+            let lights = lights.strip_prefix('[')?;
+            let (buttons, joltages) = rest.split_once('{')?;
+            let joltages = joltages.strip_suffix('}')?;
+            Some((lights, buttons, joltages))
+        })
+        .at(line, line, "expected '[lights] (buttons) {joltages}'")?;
+
+    let parser = Parser::new(line);
+    let num_lights = lights.chars().count();
+    if num_lights > 16 {
+        return Err(parser.error(lights, "a machine has at most 16 lights"));
+    }
+    let light_bits = parser.within(lights, str::parse)?;
+    let buttons = buttons
+        .split_whitespace()
+        .map(|button| {
+            let counters = parser.within(button, parse_button)?;
+            if counters.iter().any(|&counter| counter >= num_lights) {
+                return Err(parser.error(
+                    button,
+                    format!("the machine only has {} lights", num_lights),
+                ));
+            }
+            Ok(counters)
+        })
+        .collect::<Result<_, _>>()?;
+    let joltages: Vec<usize> = parser.numbers(joltages, ',')?;
+    if joltages.len() != num_lights {
+        return Err(parser.error(
+            lights,
+            format!("{} lights but {} joltages", num_lights, joltages.len()),
+        ));
+    }
+    Ok(Machine {
+        lights: light_bits,
+        buttons,
+        joltages,
+    })
+}
+
+pub fn parse_button(button: &str) -> Result<Vec<usize>, ParseError> {
+    let counters = button
+        .strip_prefix('(')
+        .and_then(|button| button.strip_suffix(')'))
+        .at(button, button, "expected a button like '(1,3)'")?;
+    Parser::new(button).numbers(counters, ',')
+}
+
+/// The fewest button presses that turn on exactly the `target` lights
+pub fn count_button_presses(target: BitSequence, buttons: &[Vec<usize>]) -> Option<usize> {
+    //breadth-first search
+    let start = BitSequence::default(); //lights are all off
+    let mut queue = VecDeque::from([start]);
+    let mut distances = HashMap::from([(start, 0usize)]);
+
+    while let Some(state) = queue.pop_front() {
+        if state == target {
+            return distances.get(&target).copied();
+        }
+        let current_dist = distances[&state];
+        for button in buttons {
+            let next_state = state.toggle_bits(button);
+            if let Vacant(entry) = distances.entry(next_state) {
+                entry.insert(current_dist + 1);
+                queue.push_back(next_state);
+            }
+        }
+    }
+    None
+}
+
+// compute costs for all button combinations, grouped by parity pattern
+// returns: parity_pattern -> list of (counts, cost) pairs
+fn button_combination_costs(
+    buttons: &[Vec<usize>],
+    num_counters: usize,
+) -> HashMap<BitSequence, Vec<(Vec<usize>, usize)>> {
+    // generate all button combinations with their parity patterns and costs
+    (0..buttons.len())
+        .powerset()
+        .map(|button_indices| {
+            let cost = button_indices.len();
+            let counts: Vec<usize> = (0..num_counters)
+                .map(|i| {
+                    button_indices
+                        .iter()
+                        .filter(|&&btn_idx| buttons[btn_idx].contains(&i))
+                        .count()
+                })
+                .collect();
+            let parity_pattern = counts
+                .iter()
+                .map(|&count| !count.is_multiple_of(2))
+                .collect();
+            (parity_pattern, (counts, cost))
+        })
+        .into_group_map()
+}
+
+fn solve_recursive(
+    goal: &[usize],
+    costs: &HashMap<BitSequence, Vec<(Vec<usize>, usize)>>,
+    cache: &mut HashMap<Vec<usize>, usize>,
+) -> usize {
+    // base case: all zeros
+    if goal.iter().all(|&x| x == 0) {
+        return 0;
+    }
+
+    if let Some(&cached) = cache.get(goal) {
+        return cached;
+    }
+
+    // get parity pattern of current goal
+    let parity_pattern: BitSequence = goal.iter().map(|x| !x.is_multiple_of(2)).collect();
+
+    // try all combinations that match this parity
+    let answer = costs
+        .get(&parity_pattern)
+        .into_iter()
+        .flatten()
+        .filter(|(counts, _)| counts.iter().zip(goal).all(|(&p, &g)| p <= g))
+        .map(|(counts, cost)| {
+            // calculate new goal: (goal - count) / 2
+            let new_goal: Vec<_> = counts
+                .iter()
+                .zip(goal)
+                .map(|(&c, &g)| (g - c) / 2)
+                .collect();
+
+            let recursive_cost = solve_recursive(&new_goal, costs, cache);
+            cost.saturating_add(recursive_cost.saturating_mul(2))
+        })
+        .min()
+        .unwrap_or(usize::MAX);
+
+    cache.insert(goal.to_owned(), answer);
+    answer
+}
+
+const MAX_BUTTONS: usize = 20;
+
+/// The fewest button presses that reach the required joltages
+pub fn solve_machine(buttons: &[Vec<usize>], joltages: &[usize]) -> Result<usize> {
+    // every combination of buttons is tried, so this bounds the time a machine takes
+    ensure!(
+        buttons.len() <= MAX_BUTTONS,
+        "a machine with {} buttons has too many combinations to try, the most is {}",
+        buttons.len(),
+        MAX_BUTTONS
+    );
+    if let Some((counter, joltage)) = joltages
+        .iter()
+        .enumerate()
+        .find(|&(counter, &joltage)| joltage > 0 && !buttons.iter().flatten().contains(&counter))
+    {
+        bail!(
+            "No button increments counter {}, which needs a joltage of {}",
+            counter,
+            joltage
+        );
+    }
+    let costs = button_combination_costs(buttons, joltages.len());
+    let mut cache = HashMap::new();
+    let answer = solve_recursive(joltages, &costs, &mut cache);
+    ensure!(
+        answer < usize::MAX,
+        "No solution found for joltages {:?}",
+        joltages
+    );
+    Ok(answer)
+}
+
+#[derive(Default)]
+pub struct Day10;
+
+inventory::submit! {
+    Puzzle::new::<Day10>(2025, 10, "Factory")
+        .with_generator::<Day10Generator>()
+        .with_reference::<Day10Naive>()
+}
+
+impl Solution for Day10 {
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../../examples/2025/day10.txt"),
+        params: &[],
+        part1: Some("7"),
+        part2: Some("33"),
+    }];
+
+    type Input = Vec<Machine>;
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let parser = Parser::new(input);
+        let machines = input
+            .lines()
+            .map(|line| parser.within(line, parse_line))
+            .collect::<Result<_, _>>()?;
+        Ok(machines)
+    }
+
+    type Part1Output = usize;
+    fn part1(&self, machines: &Self::Input) -> Result<Self::Part1Output> {
+        machines
+            .iter()
+            .map(|machine| {
+                count_button_presses(machine.lights, &machine.buttons)
+                    .context("unable to find solution")
+            })
+            .sum()
+    }
+
+    type Part2Output = usize;
+    fn part2(&self, machines: &Self::Input) -> Result<Self::Part2Output> {
+        machines.iter().try_fold(0usize, |total, machine| {
+            let presses = solve_machine(&machine.buttons, &machine.joltages)?;
+            total
+                .checked_add(presses)
+                .context("too many presses to count")
+        })
+    }
+}
+
+/// Random machines, whose lights and joltages are set from real button presses so that both parts can be solved.
+/// The `unreachable` percentage of machines get one more counter that no button increments but that needs a joltage,
+/// which leaves part 2 without a solution.
+pub struct Day10Generator {
+    machines: usize,
+    max_lights: usize,
+    max_presses: usize,
+    unreachable: u32,
+}
+
+impl Default for Day10Generator {
+    fn default() -> Self {
+        Self {
+            machines: 170,
+            max_lights: 10,
+            max_presses: 20,
+            unreachable: 0,
+        }
+    }
+}
+
+impl Generator for Day10Generator {
+    const SMALL: &'static [(&'static str, &'static str)] = &[
+        ("machines", "10"),
+        ("max_lights", "5"),
+        ("max_presses", "3"),
+        ("unreachable", "5"),
+    ];
+
+    fn knobs(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("machines", self.machines.to_string()),
+            ("max_lights", self.max_lights.to_string()),
+            ("max_presses", self.max_presses.to_string()),
+            ("unreachable", self.unreachable.to_string()),
+        ]
+    }
+
+    fn set_knob(&mut self, name: &str, value: &str) -> Result<()> {
+        match name {
+            "machines" => self.machines = parse_knob(name, value, 1..)?,
+            // the lights are kept in 16 bits
+            "max_lights" => self.max_lights = parse_knob(name, value, 1..=16)?,
+            "max_presses" => self.max_presses = parse_knob(name, value, 0..=1_000_000)?,
+            "unreachable" => self.unreachable = parse_knob(name, value, 0..=100)?,
+            _ => bail!("unknown knob '{}'", name),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut StdRng) -> String {
+        (0..self.machines)
+            .map(|_| {
+                let lights = rng.random_range(self.max_lights.div_ceil(2)..=self.max_lights);
+                let buttons: Vec<Vec<usize>> = (0..rng.random_range(1..=lights + 3))
+                    .map(|_| {
+                        let size = rng.random_range(1..=lights.div_ceil(2));
+                        let button = index::sample(rng, lights, size).into_iter();
+                        button.sorted().collect()
+                    })
+                    .collect();
+
+                let mut on = vec![false; lights];
+                let mut joltages = vec![0; lights];
+                for button in &buttons {
+                    let presses = rng.random_range(0..=self.max_presses);
+                    let toggle = rng.random_bool(0.5);
+                    for &counter in button {
+                        on[counter] ^= toggle;
+                        joltages[counter] += presses;
+                    }
+                }
+                // the lights are kept in 16 bits, so there may be no room for another counter
+                if lights < 16 && rng.random_ratio(self.unreachable, 100) {
+                    on.push(false);
+                    joltages.push(rng.random_range(1..=self.max_presses.max(1)));
+                }
+                let on: String = on.iter().map(|&on| if on { '#' } else { '.' }).collect();
+                let buttons = buttons
+                    .iter()
+                    .map(|button| format!("({})", button.iter().join(",")));
+                format!(
+                    "[{}] {} {{{}}}\n",
+                    on,
+                    buttons.format(" "),
+                    joltages.iter().join(",")
+                )
+            })
+            .collect()
+    }
+}
+
+/// Tries every combination of presses, as a reference for [`count_button_presses`] and [`solve_machine`]
+#[derive(Default)]
+pub struct Day10Naive;
+
+/// The fewest presses that turn on exactly the `target` lights, trying every set of buttons.
+/// Pressing a button twice undoes it, so no button is pressed more than once.
+pub fn naive_button_presses(target: BitSequence, buttons: &[Vec<usize>]) -> Option<usize> {
+    buttons
+        .iter()
+        .powerset()
+        .filter(|pressed| {
+            let lights = pressed
+                .iter()
+                .fold(BitSequence::default(), |lights, button| {
+                    lights.toggle_bits(button)
+                });
+            lights == target
+        })
+        .map(|pressed| pressed.len())
+        .min()
+}
+
+/// The fewest presses that reach the joltages, trying every number of presses of each button in turn
+pub fn naive_joltage_presses(buttons: &[Vec<usize>], joltages: &[usize]) -> Option<usize> {
+    let Some((button, rest)) = buttons.split_first() else {
+        return joltages.iter().all(|&joltage| joltage == 0).then_some(0);
+    };
+    // pressing the button more often than this would overshoot one of its counters
+    let most = button
+        .iter()
+        .map(|&counter| joltages.get(counter).copied().unwrap_or_default())
+        .min()
+        .unwrap_or_default();
+    (0..=most)
+        .filter_map(|presses| {
+            let mut remaining = joltages.to_vec();
+            for &counter in button {
+                if let Some(joltage) = remaining.get_mut(counter) {
+                    *joltage -= presses;
+                }
+            }
+            Some(presses + naive_joltage_presses(rest, &remaining)?)
+        })
+        .min()
+}
+
+impl Solution for Day10Naive {
+    const EXAMPLES: &'static [Example] = Day10::EXAMPLES;
+
+    type Input = Vec<Machine>;
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Day10.parse(input)
+    }
+
+    type Part1Output = usize;
+    fn part1(&self, machines: &Self::Input) -> Result<Self::Part1Output> {
+        machines
+            .iter()
+            .map(|machine| {
+                naive_button_presses(machine.lights, &machine.buttons)
+                    .context("unable to find solution")
+            })
+            .sum()
+    }
+
+    type Part2Output = usize;
+    fn part2(&self, machines: &Self::Input) -> Result<Self::Part2Output> {
+        machines
+            .iter()
+            .map(|machine| {
+                naive_joltage_presses(&machine.buttons, &machine.joltages).with_context(|| {
+                    format!("No solution found for joltages {:?}", machine.joltages)
+                })
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::check_examples;
+    use proptest::prelude::*;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day10>();
+        check_examples::<Day10Naive>();
+    }
+
+    #[test]
+    fn parse_errors_point_into_the_input() {
+        let input = "[.##.] (3) (1,3) {3,5,4,7}\n[.#] (0,a) {1,2}\n";
+        let Err(err) = Day10.parse(input) else {
+            panic!("the second machine has an invalid button");
+        };
+        let err = err.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((err.line(), err.column()), (2, 9));
+        assert_eq!(err.underline(), "[.#] (0,a) {1,2}\n        ^");
+    }
+
+    #[test]
+    fn counters_no_button_increments_are_an_error() {
+        let machines = Day10.parse("[#.] (0) {1,2}\n").unwrap();
+        let err = Day10.part2(&machines).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "No button increments counter 1, which needs a joltage of 2"
+        );
+        assert!(Day10Naive.part2(&machines).is_err());
+        // a counter that needs no joltage is fine
+        let machines = Day10.parse("[#.] (0) {1,0}\n").unwrap();
+        assert_eq!(Day10.part2(&machines).unwrap(), 1);
+    }
+
+    /// Buttons over 6 counters, along with the lights and joltages of pressing each some times.
+    /// A 7th counter that no button increments may still need a joltage, so that there is no solution.
+    fn machine() -> impl Strategy<Value = (Vec<Vec<usize>>, BitSequence, Vec<usize>)> {
+        let button = prop::collection::btree_set(0usize..6, 1..4)
+            .prop_map(|counters| counters.into_iter().collect::<Vec<_>>());
+        let buttons = prop::collection::vec((button, 0usize..4), 1..6);
+        (buttons, 0usize..3).prop_map(|(buttons, unreachable)| {
+            let mut lights = BitSequence::default();
+            let mut joltages = vec![0; 6];
+            joltages.push(unreachable);
+            for (button, presses) in &buttons {
+                for &counter in button {
+                    joltages[counter] += presses;
+                }
+                if presses % 2 == 1 {
+                    lights = lights.toggle_bits(button);
+                }
+            }
+            let buttons = buttons.into_iter().map(|(button, _)| button).collect();
+            (buttons, lights, joltages)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn presses_match_trying_every_combination((buttons, lights, joltages) in machine()) {
+            prop_assert_eq!(
+                count_button_presses(lights, &buttons),
+                naive_button_presses(lights, &buttons)
+            );
+            prop_assert_eq!(
+                solve_machine(&buttons, &joltages).ok(),
+                naive_joltage_presses(&buttons, &joltages)
+            );
+        }
+    }
+}
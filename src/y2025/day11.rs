@@ -0,0 +1,318 @@
+use crate::generate::{Generator, parse_knob};
+use crate::parse::{ParseContext, ParseError, Parser};
+use crate::registry::Puzzle;
+use crate::solution::{Example, Solution};
+use anyhow::{Context, Result, bail};
+use itertools::Itertools;
+use rand::RngExt;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+
+pub type Node = [u8; 3];
+
+pub fn parse_graph(input: &str) -> Result<HashMap<Node, Vec<Node>>, ParseError> {
+    let parser = Parser::new(input);
+    input
+        .lines()
+        .map(|line| {
+            let (node, neighbors) = parser.split_once(line, ':')?;
+            let node = node
+                .as_bytes()
+                .try_into()
+                .at(input, node, "not exactly 3 bytes")?;
+            let neighbors = neighbors
+                .split_whitespace()
+                .map(|s| s.as_bytes().try_into().at(input, s, "not exactly 3 bytes"))
+                .collect::<Result<_, _>>()?;
+            Ok((node, neighbors))
+        })
+        .collect()
+}
+
+/// The number of paths from `start` to `target`, or an error if a path can go round a loop
+pub fn count_paths(start: Node, target: Node, graph: &HashMap<Node, Vec<Node>>) -> Result<usize> {
+    // depth first search with its own stack, so that a long chain of devices can't overflow
+    // the thread's stack. `counts` holds the paths from each node already counted
+    // and `None` for the nodes on the current path.
+    let mut counts = HashMap::from([(target, Some(1))]);
+    let mut stack = Vec::new();
+    if let Entry::Vacant(entry) = counts.entry(start) {
+        entry.insert(None);
+        stack.push(Frame::new(start));
+    }
+    while let Some(frame) = stack.last_mut() {
+        let Some(&neighbor) = graph.get(&frame.node).and_then(|n| n.get(frame.next)) else {
+            let Frame { node, paths, .. } = stack.pop().expect("the stack is not empty");
+            counts.insert(node, Some(paths));
+            if let Some(parent) = stack.last_mut() {
+                parent.paths = parent.paths.checked_add(paths).context(TOO_MANY_PATHS)?;
+            }
+            continue;
+        };
+        frame.next += 1;
+        match counts.get(&neighbor) {
+            Some(Some(paths)) => {
+                frame.paths = frame.paths.checked_add(*paths).context(TOO_MANY_PATHS)?;
+            }
+            Some(None) => bail!(
+                "the devices loop back to {}",
+                String::from_utf8_lossy(&neighbor)
+            ),
+            None => {
+                counts.insert(neighbor, None);
+                stack.push(Frame::new(neighbor));
+            }
+        }
+    }
+    Ok(counts[&start].expect("every node visited has been counted"))
+}
+
+/// A node being searched, with how many of its neighbors have been counted
+/// and the paths through them
+struct Frame {
+    node: Node,
+    next: usize,
+    paths: usize,
+}
+
+impl Frame {
+    fn new(node: Node) -> Self {
+        Self {
+            node,
+            next: 0,
+            paths: 0,
+        }
+    }
+}
+
+const TOO_MANY_PATHS: &str = "too many paths to count";
+
+#[derive(Default)]
+pub struct Day11;
+
+inventory::submit! {
+    Puzzle::new::<Day11>(2025, 11, "Reactor")
+        .with_generator::<Day11Generator>()
+        .with_reference::<Day11Naive>()
+}
+
+impl Solution for Day11 {
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: include_str!("../../examples/2025/day11.txt"),
+            params: &[],
+            part1: Some("5"),
+            part2: None,
+        },
+        // part 2 starts from a different node, so it has its own example
+        Example {
+            input: include_str!("../../examples/2025/day11-2.txt"),
+            params: &[],
+            part1: None,
+            part2: Some("2"),
+        },
+    ];
+
+    type Input = HashMap<Node, Vec<Node>>;
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse_graph(input)?)
+    }
+
+    type Part1Output = usize;
+    fn part1(&self, graph: &Self::Input) -> Result<Self::Part1Output> {
+        count_paths(*b"you", *b"out", graph)
+    }
+
+    type Part2Output = usize;
+    fn part2(&self, graph: &Self::Input) -> Result<Self::Part2Output> {
+        // the paths through both in either order, as svr → dac → fft → out or svr → fft → dac → out
+        let paths_through = |[a, b, c, d]: [&[u8; 3]; 4]| -> Result<usize> {
+            let first = count_paths(*a, *b, graph)?;
+            let second = count_paths(*b, *c, graph)?;
+            let third = count_paths(*c, *d, graph)?;
+            first
+                .checked_mul(second)
+                .and_then(|paths| paths.checked_mul(third))
+                .context(TOO_MANY_PATHS)
+        };
+        let paths_via_dac_first = paths_through([b"svr", b"dac", b"fft", b"out"])?;
+        let paths_via_fft_first = paths_through([b"svr", b"fft", b"dac", b"out"])?;
+        paths_via_dac_first
+            .checked_add(paths_via_fft_first)
+            .context(TOO_MANY_PATHS)
+    }
+}
+
+/// A random network of devices without loops, where every device leads to `out`
+pub struct Day11Generator {
+    devices: usize,
+    max_outputs: usize,
+}
+
+impl Default for Day11Generator {
+    fn default() -> Self {
+        Self {
+            devices: 600,
+            max_outputs: 3,
+        }
+    }
+}
+
+/// Outputs only go to devices this close in the order they are laid out, giving long paths
+const OUTPUT_REACH: usize = 20;
+
+/// The number of paths from any one device, kept low enough that the answers fit in 64 bits
+const MAX_PATHS: usize = 1 << 50;
+
+impl Generator for Day11Generator {
+    const SMALL: &'static [(&'static str, &'static str)] =
+        &[("devices", "14"), ("max_outputs", "3")];
+
+    fn knobs(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("devices", self.devices.to_string()),
+            ("max_outputs", self.max_outputs.to_string()),
+        ]
+    }
+
+    fn set_knob(&mut self, name: &str, value: &str) -> Result<()> {
+        match name {
+            // the named devices, and no more than there are three-letter names for
+            "devices" => self.devices = parse_knob(name, value, 5..=26 * 26 * 26)?,
+            "max_outputs" => self.max_outputs = parse_knob(name, value, 1..=OUTPUT_REACH)?,
+            _ => bail!("unknown knob '{}'", name),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut StdRng) -> String {
+        let named = [*b"you", *b"svr", *b"dac", *b"fft", *b"out"];
+        let mut names = HashSet::from(named);
+        let mut devices = vec![*b"svr"];
+        while names.len() < self.devices {
+            let name = [(); 3].map(|_| rng.random_range(b'a'..=b'z'));
+            if names.insert(name) {
+                devices.push(name);
+            }
+        }
+        devices.push(*b"out");
+        for name in [*b"you", *b"dac", *b"fft"] {
+            let position = rng.random_range(1..devices.len());
+            devices.insert(position, name);
+        }
+
+        // outputs only lead further along the devices, so there are no loops,
+        // and each device leads to the next so that `out` can always be reached
+        let mut paths = vec![1; devices.len()];
+        let mut lines = Vec::new();
+        for i in (0..devices.len() - 1).rev() {
+            let mut outputs = vec![i + 1];
+            paths[i] = 1 + paths[i + 1];
+            for _ in 1..rng.random_range(1..=self.max_outputs) {
+                let output = rng.random_range(i + 1..(i + 1 + OUTPUT_REACH).min(devices.len()));
+                if !outputs.contains(&output) && paths[i] + paths[output] <= MAX_PATHS {
+                    outputs.push(output);
+                    paths[i] += paths[output];
+                }
+            }
+            outputs.shuffle(rng);
+            let name = |i: usize| String::from_utf8_lossy(&devices[i]).into_owned();
+            lines.push(format!(
+                "{}: {}\n",
+                name(i),
+                outputs.into_iter().map(name).join(" ")
+            ));
+        }
+        lines.shuffle(rng);
+        lines.concat()
+    }
+}
+
+/// Walks every path one at a time, as a reference for the memoized [`count_paths`]
+#[derive(Default)]
+pub struct Day11Naive;
+
+/// Calls `visit` with every path from the end of `path` to `target`
+fn for_each_path(
+    graph: &HashMap<Node, Vec<Node>>,
+    path: &mut Vec<Node>,
+    target: Node,
+    visit: &mut impl FnMut(&[Node]),
+) {
+    let node = *path.last().expect("a path starts with a node");
+    if node == target {
+        visit(path);
+        return;
+    }
+    for &next in graph.get(&node).into_iter().flatten() {
+        path.push(next);
+        for_each_path(graph, path, target, visit);
+        path.pop();
+    }
+}
+
+impl Solution for Day11Naive {
+    const EXAMPLES: &'static [Example] = Day11::EXAMPLES;
+
+    type Input = HashMap<Node, Vec<Node>>;
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse_graph(input)?)
+    }
+
+    type Part1Output = usize;
+    fn part1(&self, graph: &Self::Input) -> Result<Self::Part1Output> {
+        let mut count = 0;
+        for_each_path(graph, &mut vec![*b"you"], *b"out", &mut |_| count += 1);
+        Ok(count)
+    }
+
+    type Part2Output = usize;
+    fn part2(&self, graph: &Self::Input) -> Result<Self::Part2Output> {
+        let mut count = 0;
+        for_each_path(graph, &mut vec![*b"svr"], *b"out", &mut |path| {
+            if path.contains(b"dac") && path.contains(b"fft") {
+                count += 1;
+            }
+        });
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::check_examples;
+    use itertools::iproduct;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day11>();
+        check_examples::<Day11Naive>();
+    }
+
+    #[test]
+    fn loops_are_an_error() {
+        let graph = Day11.parse("you: aaa\naaa: bbb\nbbb: aaa out\n").unwrap();
+        assert!(Day11.part1(&graph).is_err());
+    }
+
+    #[test]
+    fn long_chains_do_not_overflow_the_stack() {
+        let letters = b'a'..=b'z';
+        let names = iproduct!(letters.clone(), letters.clone(), letters)
+            .map(|(a, b, c)| String::from_utf8(vec![a, b, c]).unwrap())
+            .filter(|name| name != "you" && name != "out");
+        let chain: Vec<_> = std::iter::once("you".to_string())
+            .chain(names)
+            .chain(std::iter::once("out".to_string()))
+            .collect();
+        let input: String = chain
+            .windows(2)
+            .map(|pair| format!("{}: {}\n", pair[0], pair[1]))
+            .collect();
+        let graph = Day11.parse(&input).unwrap();
+        assert_eq!(Day11.part1(&graph).unwrap(), 1);
+    }
+}
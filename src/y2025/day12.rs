@@ -0,0 +1,400 @@
+use crate::generate::{Generator, parse_knob};
+use crate::parse::{ParseContext, ParseError, Parser};
+use crate::registry::Puzzle;
+use crate::solution::{Example, Solution};
+use anyhow::{Result, bail, ensure};
+use itertools::{Itertools, iproduct};
+use rand::RngExt;
+use rand::rngs::StdRng;
+
+pub struct Region {
+    pub width: usize,
+    pub length: usize,
+    /// How many presents of each shape have to fit in the region
+    pub counts: Vec<usize>,
+}
+
+fn parse_regions(input: &str) -> Result<Vec<Region>, ParseError> {
+    let parser = Parser::new(input);
+    // we can ignore all the present shapes
+    let regions = parser
+        .sections(input)
+        .last()
+        .at(input, input, "unable to parse input")?;
+
+    regions
+        .lines()
+        .map(|line| {
+            let (dimensions, counts) = parser.split_once(line, ':')?;
+            let (width, length) = parser.split_once(dimensions, 'x')?;
+            let width = parser.number(width)?;
+            let length = parser.number(length)?;
+            let counts = parser.spaced_numbers(counts)?;
+
+            Ok(Region {
+                width,
+                length,
+                counts,
+            })
+        })
+        .collect()
+}
+
+#[derive(Default)]
+pub struct Day12;
+
+inventory::submit! {
+    Puzzle::new::<Day12>(2025, 12, "Christmas Tree Farm")
+        .with_generator::<Day12Generator>()
+        .with_reference::<Day12Naive>()
+}
+
+impl Solution for Day12 {
+    // the 3x3 shortcut in part 1 only holds for the real input, so only Day12Naive can solve the example
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../../examples/2025/day12.txt"),
+        params: &[],
+        part1: None,
+        part2: None,
+    }];
+    const HAS_PART2: bool = false;
+
+    type Input = Vec<Region>;
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse_regions(input)?)
+    }
+
+    type Part1Output = usize;
+    fn part1(&self, regions: &Self::Input) -> Result<Self::Part1Output> {
+        // today the problem is really hard in general,
+        // but the input only contains special cases which make it easy
+        let count = regions
+            .iter()
+            .filter(|region| {
+                // widened so that no region or count can overflow
+                let total_count: u128 = region.counts.iter().map(|&count| count as u128).sum();
+
+                // all the presents fit in a 3x3 square,
+                // so as long as the region can fit total_count squares, then we're good
+                // more complicated situations never occur in the input
+                region.width as u128 * region.length as u128 >= 9 * total_count
+            })
+            .count();
+        Ok(count)
+    }
+
+    type Part2Output = usize;
+    fn part2(&self, _regions: &Self::Input) -> Result<Self::Part2Output> {
+        bail!("there is no part 2 on the last day")
+    }
+}
+
+/// Random presents and regions, which either have room for every present in its own 3x3 square
+/// or are smaller than the presents put together, like the regions in the real input
+pub struct Day12Generator {
+    shapes: usize,
+    regions: usize,
+    min_size: usize,
+    max_size: usize,
+}
+
+impl Default for Day12Generator {
+    fn default() -> Self {
+        Self {
+            shapes: 6,
+            regions: 1000,
+            min_size: 35,
+            max_size: 50,
+        }
+    }
+}
+
+impl Generator for Day12Generator {
+    const SMALL: &'static [(&'static str, &'static str)] = &[
+        ("shapes", "4"),
+        ("regions", "5"),
+        ("min_size", "3"),
+        ("max_size", "8"),
+    ];
+
+    fn knobs(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("shapes", self.shapes.to_string()),
+            ("regions", self.regions.to_string()),
+            ("min_size", self.min_size.to_string()),
+            ("max_size", self.max_size.to_string()),
+        ]
+    }
+
+    fn set_knob(&mut self, name: &str, value: &str) -> Result<()> {
+        match name {
+            "shapes" => self.shapes = parse_knob(name, value, 1..)?,
+            "regions" => self.regions = parse_knob(name, value, 1..)?,
+            "min_size" => self.min_size = parse_knob(name, value, 1..=1_000_000)?,
+            "max_size" => self.max_size = parse_knob(name, value, 1..=1_000_000)?,
+            _ => bail!("unknown knob '{}'", name),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut StdRng) -> String {
+        let mut input = String::new();
+        let mut sizes = Vec::new();
+        for i in 0..self.shapes {
+            // the middle is always part of the present, so that every present has a cell
+            let cells: Vec<bool> = (0..9).map(|j| j == 4 || rng.random_bool(0.7)).collect();
+            sizes.push(cells.iter().filter(|&&cell| cell).count());
+            input += &format!("{}:\n", i);
+            for row in cells.chunks(3) {
+                input.extend(row.iter().map(|&cell| if cell { '#' } else { '.' }));
+                input.push('\n');
+            }
+            input.push('\n');
+        }
+
+        let max_size = self.max_size.max(self.min_size);
+        for _ in 0..self.regions {
+            let width = rng.random_range(self.min_size..=max_size);
+            let length = rng.random_range(self.min_size..=max_size);
+            let mut counts = vec![0; self.shapes];
+            if rng.random_bool(0.5) {
+                let squares = (width / 3) * (length / 3);
+                for _ in 0..rng.random_range(0..=squares) {
+                    counts[rng.random_range(0..self.shapes)] += 1;
+                }
+            } else {
+                let mut cells = 0;
+                while cells <= width * length {
+                    let shape = rng.random_range(0..self.shapes);
+                    counts[shape] += 1;
+                    cells += sizes[shape];
+                }
+            }
+            input += &format!("{}x{}: {}\n", width, length, counts.iter().join(" "));
+        }
+        input
+    }
+}
+
+/// Searches for a way to pack the presents into each region, as a reference for the 3x3 shortcut,
+/// which unlike the shortcut also solves the example
+#[derive(Default)]
+pub struct Day12Naive;
+
+/// The cells of a present as `(row, column)`, in reading order
+type Shape = Vec<(usize, usize)>;
+
+pub struct Farm {
+    /// Every distinct way each present can be turned or flipped
+    pub orientations: Vec<Vec<Shape>>,
+    pub regions: Vec<Region>,
+}
+
+fn parse_shape(parser: Parser, section: &str) -> Result<Shape, ParseError> {
+    let (_, grid) = parser.split_once(section, '\n')?;
+    let cells = parser.grid(
+        grid,
+        |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        },
+        "expected '#' or '.'",
+    )?;
+    Ok(iproduct!(0..cells.len(), 0..3)
+        .filter(|&(row, column)| cells[row].get(column).copied().unwrap_or_default())
+        .collect())
+}
+
+/// Every distinct rotation and reflection of the shape, moved to the top left
+fn orientations(shape: &Shape) -> Vec<Shape> {
+    (0..8)
+        .map(|transform| {
+            let moved: Vec<(isize, isize)> = shape
+                .iter()
+                .map(|&(row, column)| {
+                    let (row, column) = (row as isize, column as isize);
+                    let (row, column) = if transform & 4 == 0 {
+                        (row, column)
+                    } else {
+                        (column, row)
+                    };
+                    match transform % 4 {
+                        0 => (row, column),
+                        1 => (column, -row),
+                        2 => (-row, -column),
+                        _ => (-column, row),
+                    }
+                })
+                .collect();
+            let top = moved.iter().map(|&(row, _)| row).min().unwrap_or_default();
+            let left = moved
+                .iter()
+                .map(|&(_, column)| column)
+                .min()
+                .unwrap_or_default();
+            moved
+                .into_iter()
+                .map(|(row, column)| ((row - top) as usize, (column - left) as usize))
+                .sorted()
+                .collect()
+        })
+        .sorted()
+        .dedup()
+        .collect()
+}
+
+/// The largest region the packing search can handle, as it keeps the region in a `u128`
+const MAX_AREA: usize = 128;
+
+/// A search that fills the region one cell at a time, in reading order
+struct Packing {
+    /// The cells each present covers when its first cell is on each cell of the region, if it fits
+    placements: Vec<Vec<Vec<u128>>>,
+    counts: Vec<usize>,
+    /// How many more cells may be left empty
+    spare: usize,
+}
+
+impl Packing {
+    /// Whether the remaining presents fit, given that the cells before the first free one are all
+    /// decided
+    fn search(&mut self, filled: u128) -> bool {
+        if self.counts.iter().all(|&count| count == 0) {
+            return true;
+        }
+        let cell = (!filled).trailing_zeros() as usize;
+        if cell == MAX_AREA {
+            return false;
+        }
+        // either the cell stays empty
+        if self.spare > 0 {
+            self.spare -= 1;
+            let found = self.search(filled | 1 << cell);
+            self.spare += 1;
+            if found {
+                return true;
+            }
+        }
+        // or it is the first cell of a present
+        for shape in 0..self.counts.len() {
+            if self.counts[shape] == 0 {
+                continue;
+            }
+            for i in 0..self.placements[shape][cell].len() {
+                let placement = self.placements[shape][cell][i];
+                if placement & filled != 0 {
+                    continue;
+                }
+                self.counts[shape] -= 1;
+                let found = self.search(filled | placement);
+                self.counts[shape] += 1;
+                if found {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+}
+
+/// Where each orientation of the present can go with its first cell on `cell`
+fn placements(region: &Region, orientations: &[Shape], cell: usize) -> Vec<u128> {
+    let (row, column) = (cell / region.width, cell % region.width);
+    orientations
+        .iter()
+        .filter_map(|orientation| {
+            let &(first_row, first_column) = orientation.first()?;
+            orientation.iter().try_fold(0, |placement, &(r, c)| {
+                let r = (row + r).checked_sub(first_row)?;
+                let c = (column + c).checked_sub(first_column)?;
+                (r < region.length && c < region.width)
+                    .then_some(placement | 1 << (r * region.width + c))
+            })
+        })
+        .collect()
+}
+
+/// Whether the presents can all be packed into the region without overlapping
+pub fn can_pack(region: &Region, orientations: &[Vec<Shape>]) -> Result<bool> {
+    let area = region.width.saturating_mul(region.length);
+    ensure!(
+        area <= MAX_AREA,
+        "a {}x{} region is too large to search, the most is {} cells",
+        region.width,
+        region.length,
+        MAX_AREA
+    );
+    let cells: usize = region
+        .counts
+        .iter()
+        .zip(orientations)
+        .map(|(count, shape)| count.saturating_mul(shape.first().map_or(0, Vec::len)))
+        .fold(0, usize::saturating_add);
+    if cells > area || region.counts.len() > orientations.len() {
+        return Ok(false);
+    }
+    let placements = orientations
+        .iter()
+        .map(|shape| {
+            (0..area)
+                .map(|cell| placements(region, shape, cell))
+                .collect()
+        })
+        .collect();
+    let mut packing = Packing {
+        placements,
+        counts: region.counts.clone(),
+        spare: area - cells,
+    };
+    // the cells past the region count as filled
+    let outside = u128::MAX.checked_shl(area as u32).unwrap_or_default();
+    Ok(packing.search(outside))
+}
+
+impl Solution for Day12Naive {
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../../examples/2025/day12.txt"),
+        params: &[],
+        part1: Some("2"),
+        part2: None,
+    }];
+    const HAS_PART2: bool = false;
+
+    type Input = Farm;
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let parser = Parser::new(input);
+        let sections: Vec<_> = parser.sections(input).collect();
+        let shapes = sections[..sections.len() - 1]
+            .iter()
+            .map(|section| parse_shape(parser, section))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Farm {
+            orientations: shapes.iter().map(orientations).collect(),
+            regions: parse_regions(input)?,
+        })
+    }
+
+    type Part1Output = usize;
+    fn part1(&self, farm: &Self::Input) -> Result<Self::Part1Output> {
+        farm.regions.iter().try_fold(0, |count, region| {
+            Ok(count + can_pack(region, &farm.orientations)? as usize)
+        })
+    }
+
+    type Part2Output = usize;
+    fn part2(&self, _farm: &Self::Input) -> Result<Self::Part2Output> {
+        bail!("there is no part 2 on the last day")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::check_examples;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day12Naive>();
+    }
+}
@@ -0,0 +1,240 @@
+use crate::generate::{Generator, number_with_digits, parse_knob};
+use crate::parse::{ParseError, Parser};
+use crate::registry::Puzzle;
+use crate::solution::{Example, Solution};
+use anyhow::{Context, Result, bail};
+use itertools::Itertools;
+use rand::RngExt;
+use rand::rngs::StdRng;
+use std::ops::RangeInclusive;
+
+fn count_digits(id: usize) -> usize {
+    id.checked_ilog10().map(|log| log + 1).unwrap_or(1) as usize
+}
+
+/// The numbers in `range` made of one chunk of digits repeated `repetition_count` times
+pub fn repeated_numbers(
+    range: RangeInclusive<usize>,
+    repetition_count: usize,
+) -> impl Iterator<Item = usize> {
+    let &start = range.start();
+    let &end = range.end();
+
+    // Numbers with different digit counts repeat chunks of different lengths
+    (count_digits(start)..=count_digits(end))
+        .filter(move |digit_count| digit_count.is_multiple_of(repetition_count))
+        .flat_map(move |digit_count| {
+            // Calculate the number of digits in the repeated chunk
+            let chunk_digit_count = digit_count / repetition_count;
+
+            // Calculate the factor we will multiply our base number by,
+            // a 1 at the end of each chunk (10^digit_count may not fit in a usize)
+            let pow10_section = 10usize.pow(chunk_digit_count as u32);
+            let factor = (0..repetition_count as u32)
+                .map(|i| pow10_section.pow(i))
+                .sum::<usize>();
+
+            // The base number must have chunk_digit_count digits
+            let min_base_from_digits = 10usize.pow((chunk_digit_count - 1) as u32);
+            let max_base_from_digits = pow10_section - 1;
+
+            // The base number must also produce values within our range
+            let min_k_from_range = start.div_ceil(factor);
+            let max_k_from_range = end / factor;
+
+            // Combine both constraints
+            let min_base = min_base_from_digits.max(min_k_from_range);
+            let max_base = max_base_from_digits.min(max_k_from_range);
+
+            (min_base..=max_base).map(move |base_num| base_num * factor)
+        })
+}
+
+/// The numbers in `range` made of one chunk of digits repeated at least twice
+pub fn invalid_numbers(range: RangeInclusive<usize>) -> impl Iterator<Item = usize> {
+    let max_digit_count = count_digits(*range.end());
+    (2..=max_digit_count)
+        .map(move |num| repeated_numbers(range.clone(), num))
+        .kmerge()
+        .dedup()
+}
+
+pub fn parse_ranges(input: &str) -> Result<Vec<RangeInclusive<usize>>, ParseError> {
+    let parser = Parser::new(input);
+    input
+        .split(',')
+        .map(|range| parser.range(range.trim()))
+        .collect()
+}
+
+fn solve<F, I>(ranges: &[RangeInclusive<usize>], invalid_number_generator: F) -> Result<usize>
+where
+    F: Fn(RangeInclusive<usize>) -> I,
+    I: Iterator<Item = usize>,
+{
+    ranges
+        .iter()
+        .flat_map(|range| invalid_number_generator(range.clone()))
+        .try_fold(0usize, |sum, id| sum.checked_add(id))
+        .context("the invalid IDs add up to more than fits in a usize")
+}
+
+#[derive(Default)]
+pub struct Day2;
+
+inventory::submit! {
+    Puzzle::new::<Day2>(2025, 2, "Gift Shop")
+        .with_generator::<Day2Generator>()
+        .with_reference::<Day2Naive>()
+}
+
+impl Solution for Day2 {
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../../examples/2025/day2.txt"),
+        params: &[],
+        part1: Some("1227775554"),
+        part2: Some("4174379265"),
+    }];
+
+    type Input = Vec<RangeInclusive<usize>>;
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse_ranges(input)?)
+    }
+
+    type Part1Output = usize;
+    fn part1(&self, ranges: &Self::Input) -> Result<Self::Part1Output> {
+        solve(ranges, |range| repeated_numbers(range, 2))
+    }
+
+    type Part2Output = usize;
+    fn part2(&self, ranges: &Self::Input) -> Result<Self::Part2Output> {
+        solve(ranges, invalid_numbers)
+    }
+}
+
+/// Random ID ranges, each ending with at most one more digit than it starts with like the real ones
+pub struct Day2Generator {
+    ranges: usize,
+    max_digits: u32,
+    max_width: usize,
+}
+
+impl Default for Day2Generator {
+    fn default() -> Self {
+        Self {
+            ranges: 35,
+            max_digits: 10,
+            max_width: 100_000,
+        }
+    }
+}
+
+impl Generator for Day2Generator {
+    const SMALL: &'static [(&'static str, &'static str)] =
+        &[("ranges", "5"), ("max_digits", "6"), ("max_width", "1000")];
+
+    fn knobs(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("ranges", self.ranges.to_string()),
+            ("max_digits", self.max_digits.to_string()),
+            ("max_width", self.max_width.to_string()),
+        ]
+    }
+
+    fn set_knob(&mut self, name: &str, value: &str) -> Result<()> {
+        match name {
+            "ranges" => self.ranges = parse_knob(name, value, 1..)?,
+            // sums of larger IDs no longer fit in 64 bits
+            "max_digits" => self.max_digits = parse_knob(name, value, 2..=16)?,
+            "max_width" => self.max_width = parse_knob(name, value, 0..=usize::MAX / 2)?,
+            _ => bail!("unknown knob '{}'", name),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut StdRng) -> String {
+        let ranges = (0..self.ranges).map(|_| {
+            let digits = rng.random_range(2..=self.max_digits);
+            let start = number_with_digits(rng, digits);
+            // at most doubling the start keeps the end within one more digit
+            let end = start + rng.random_range(0..=self.max_width.min(start));
+            format!("{}-{}", start, end)
+        });
+        ranges.collect::<Vec<_>>().join(",") + "\n"
+    }
+}
+
+/// Checks every ID in the ranges, as a reference for the arithmetic in [`repeated_numbers`]
+#[derive(Default)]
+pub struct Day2Naive;
+
+/// Whether the ID's digits are one chunk repeated `repetitions` times
+pub fn is_repeated(id: usize, repetitions: usize) -> bool {
+    let digits = id.to_string();
+    digits.len().is_multiple_of(repetitions)
+        && digits == digits[..digits.len() / repetitions].repeat(repetitions)
+}
+
+impl Solution for Day2Naive {
+    const EXAMPLES: &'static [Example] = Day2::EXAMPLES;
+
+    type Input = Vec<RangeInclusive<usize>>;
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse_ranges(input)?)
+    }
+
+    type Part1Output = usize;
+    fn part1(&self, ranges: &Self::Input) -> Result<Self::Part1Output> {
+        let ids = ranges.iter().cloned().flatten();
+        Ok(ids.filter(|&id| is_repeated(id, 2)).sum())
+    }
+
+    type Part2Output = usize;
+    fn part2(&self, ranges: &Self::Input) -> Result<Self::Part2Output> {
+        let ids = ranges.iter().cloned().flatten();
+        let invalid = ids.filter(|&id| (2..=id.to_string().len()).any(|n| is_repeated(id, n)));
+        Ok(invalid.sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::check_examples;
+    use proptest::prelude::*;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day2>();
+        check_examples::<Day2Naive>();
+    }
+
+    #[test]
+    fn invalid_numbers_in_ranges() {
+        assert_eq!(repeated_numbers(11..=22, 2).collect::<Vec<_>>(), [11, 22]);
+        assert_eq!(repeated_numbers(95..=115, 2).collect::<Vec<_>>(), [99]);
+        assert_eq!(invalid_numbers(95..=115).collect::<Vec<_>>(), [99, 111]);
+        assert_eq!(invalid_numbers(998..=1012).collect::<Vec<_>>(), [999, 1010]);
+    }
+
+    #[test]
+    fn ranges_spanning_digit_counts_repeat_chunks_of_every_length() {
+        // both two and four digit numbers, which repeat chunks of one and two digits
+        let expected: Vec<_> = (1..=9).map(|digit| digit * 11).chain([1010]).collect();
+        assert_eq!(repeated_numbers(5..=1012, 2).collect::<Vec<_>>(), expected);
+        // too few digits to repeat at all
+        assert_eq!(repeated_numbers(1..=9, 2).count(), 0);
+    }
+
+    proptest! {
+        #[test]
+        fn invalid_numbers_match_checking_every_id(a in 1usize..20_000, b in 1usize..20_000) {
+            let range = a.min(b)..=a.max(b);
+            let expected: Vec<_> = range
+                .clone()
+                .filter(|&id| (2..=count_digits(id)).any(|n| is_repeated(id, n)))
+                .collect();
+            prop_assert_eq!(invalid_numbers(range).collect::<Vec<_>>(), expected);
+        }
+    }
+}
@@ -0,0 +1,274 @@
+use crate::generate::{Generator, parse_knob};
+use crate::parse::{ParseError, Parser};
+use crate::registry::Puzzle;
+use crate::solution::{Example, Solution, parse_param};
+use anyhow::{Context, Result, bail, ensure};
+use itertools::Itertools;
+use rand::RngExt;
+use rand::rngs::StdRng;
+use std::ops::Range;
+
+pub struct Day3 {
+    part1_batteries: usize,
+    part2_batteries: usize,
+}
+
+impl Default for Day3 {
+    fn default() -> Self {
+        Self {
+            part1_batteries: 2,
+            part2_batteries: 12,
+        }
+    }
+}
+
+inventory::submit! {
+    Puzzle::new::<Day3>(2025, 3, "Lobby")
+        .with_generator::<Day3Generator>()
+        .with_reference::<Day3Naive>()
+}
+
+impl Solution for Day3 {
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../../examples/2025/day3.txt"),
+        params: &[],
+        part1: Some("357"),
+        part2: Some("3121910778619"),
+    }];
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("part1_batteries", self.part1_batteries.to_string()),
+            ("part2_batteries", self.part2_batteries.to_string()),
+        ]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<()> {
+        let num_batteries = match name {
+            "part1_batteries" => &mut self.part1_batteries,
+            "part2_batteries" => &mut self.part2_batteries,
+            _ => bail!("unknown parameter '{}'", name),
+        };
+        *num_batteries = parse_param(name, value)?;
+        // 19 digits is as many as always fit in a u64
+        ensure!(
+            (1..=19).contains(num_batteries),
+            "{} must be from 1 to 19",
+            name
+        );
+        Ok(())
+    }
+
+    type Input = Vec<Vec<u32>>;
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let parser = Parser::new(input);
+        let banks = input
+            .lines()
+            .map(|bank| parser.within(bank, parse_bank))
+            .collect::<Result<_, _>>()?;
+        Ok(banks)
+    }
+
+    type Part1Output = u64;
+    fn part1(&self, banks: &Self::Input) -> Result<Self::Part1Output> {
+        solve(banks, self.part1_batteries)
+    }
+
+    type Part2Output = u64;
+    fn part2(&self, banks: &Self::Input) -> Result<Self::Part2Output> {
+        solve(banks, self.part2_batteries)
+    }
+}
+
+fn solve(banks: &[Vec<u32>], num_batteries: usize) -> Result<u64> {
+    banks.iter().try_fold(0u64, |total, bank| {
+        total
+            .checked_add(joltage(bank, num_batteries)?)
+            .context("the total joltage is too large")
+    })
+}
+
+pub fn parse_bank(bank: &str) -> Result<Vec<u32>, ParseError> {
+    Parser::new(bank).cells(bank, |c| c.to_digit(10), "expected a digit")
+}
+
+/// The largest number formed by `num_batteries` digits of the bank, kept in order
+pub fn joltage(bank: &[u32], num_batteries: usize) -> Result<u64> {
+    ensure!(
+        (1..=bank.len()).contains(&num_batteries),
+        "a bank has fewer than {} batteries",
+        num_batteries
+    );
+    partial_joltage(bank, 0..bank.len() - num_batteries + 1)
+}
+
+fn partial_joltage(bank: &[u32], range: Range<usize>) -> Result<u64> {
+    let start = range.start;
+    let end = range.end;
+    let (max_index, max_value) =
+        max_with_index(&bank[range]).context("cannot find maximum of an empty slice")?;
+    let max_value = max_value as u64;
+    if end == bank.len() {
+        return Ok(max_value);
+    }
+    let next_range = (start + max_index + 1)..(end + 1);
+    let power = (bank.len() - end) as u32;
+    let addend = max_value * 10u64.pow(power);
+    partial_joltage(bank, next_range).map(|joltage| addend + joltage)
+}
+
+fn max_with_index(nums: &[u32]) -> Option<(usize, u32)> {
+    nums.iter()
+        .enumerate()
+        .rev() //We reverse to get the first max in case of ties
+        .max_by_key(|&(_, &value)| value)
+        .map(|(i, max)| (i, *max))
+}
+
+/// Random banks of batteries rated from 1 to 9
+pub struct Day3Generator {
+    banks: usize,
+    batteries: usize,
+}
+
+impl Default for Day3Generator {
+    fn default() -> Self {
+        Self {
+            banks: 200,
+            batteries: 100,
+        }
+    }
+}
+
+impl Generator for Day3Generator {
+    const SMALL: &'static [(&'static str, &'static str)] = &[("banks", "5"), ("batteries", "14")];
+
+    fn knobs(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("banks", self.banks.to_string()),
+            ("batteries", self.batteries.to_string()),
+        ]
+    }
+
+    fn set_knob(&mut self, name: &str, value: &str) -> Result<()> {
+        match name {
+            "banks" => self.banks = parse_knob(name, value, 1..)?,
+            // part 2 turns on 12 batteries in each bank
+            "batteries" => self.batteries = parse_knob(name, value, 12..)?,
+            _ => bail!("unknown knob '{}'", name),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut StdRng) -> String {
+        (0..self.banks)
+            .map(|_| {
+                let bank: String = (0..self.batteries)
+                    .map(|_| char::from(b'0' + rng.random_range(1..=9)))
+                    .collect();
+                bank + "\n"
+            })
+            .collect()
+    }
+}
+
+/// Tries every choice of batteries, as a reference for the greedy [`joltage`]
+#[derive(Default)]
+pub struct Day3Naive(Day3);
+
+/// The largest number formed by `num_batteries` digits of the bank, found by trying every combination
+pub fn naive_joltage(bank: &[u32], num_batteries: usize) -> Result<u64> {
+    bank.iter()
+        .combinations(num_batteries)
+        .map(|digits| {
+            digits
+                .iter()
+                .fold(0, |joltage, &&digit| joltage * 10 + digit as u64)
+        })
+        .max()
+        .with_context(|| format!("a bank has fewer than {} batteries", num_batteries))
+}
+
+impl Solution for Day3Naive {
+    const EXAMPLES: &'static [Example] = Day3::EXAMPLES;
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        self.0.params()
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<()> {
+        self.0.set_param(name, value)
+    }
+
+    type Input = Vec<Vec<u32>>;
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        self.0.parse(input)
+    }
+
+    type Part1Output = u64;
+    fn part1(&self, banks: &Self::Input) -> Result<Self::Part1Output> {
+        let batteries = self.0.part1_batteries;
+        banks
+            .iter()
+            .map(|bank| naive_joltage(bank, batteries))
+            .sum()
+    }
+
+    type Part2Output = u64;
+    fn part2(&self, banks: &Self::Input) -> Result<Self::Part2Output> {
+        let batteries = self.0.part2_batteries;
+        banks
+            .iter()
+            .map(|bank| naive_joltage(bank, batteries))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::check_examples;
+    use proptest::prelude::*;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day3>();
+        check_examples::<Day3Naive>();
+    }
+
+    #[test]
+    fn banks_with_too_few_batteries_are_an_error() {
+        let banks = Day3::default().parse("12345\n").unwrap();
+        assert!(Day3::default().part1(&banks).is_ok());
+        assert!(Day3::default().part2(&banks).is_err());
+    }
+
+    #[test]
+    fn joltage_of_each_bank() {
+        let banks = [
+            ("987654321111111", 98, 987654321111),
+            ("811111111111119", 89, 811111111119),
+            ("234234234234278", 78, 434234234278),
+            ("818181911112111", 92, 888911112111),
+        ];
+        for (bank, two, twelve) in banks {
+            let bank = parse_bank(bank).unwrap();
+            assert_eq!(joltage(&bank, 2).unwrap(), two);
+            assert_eq!(joltage(&bank, 12).unwrap(), twelve);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn joltage_matches_trying_every_choice(
+            bank in prop::collection::vec(1u32..10, 1..16),
+            num_batteries in 1usize..6,
+        ) {
+            prop_assume!(num_batteries <= bank.len());
+            prop_assert_eq!(
+                joltage(&bank, num_batteries).unwrap(),
+                naive_joltage(&bank, num_batteries).unwrap()
+            );
+        }
+    }
+}
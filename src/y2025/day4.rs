@@ -0,0 +1,259 @@
+use crate::generate::{Generator, parse_knob};
+use crate::parse::{ParseError, Parser};
+use crate::registry::Puzzle;
+use crate::solution::{Example, Solution};
+use anyhow::{Result, bail};
+use itertools::iproduct;
+use rand::RngExt;
+use rand::rngs::StdRng;
+
+/// A grid of paper rolls, where `@` is occupied and `.` is empty
+#[derive(Clone)]
+pub struct Grid {
+    contents: Vec<bool>,
+    row_length: usize,
+    col_length: usize,
+}
+
+impl Grid {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let rows = Parser::new(input).grid(
+            input,
+            |c| match c {
+                '@' => Some(true),
+                '.' => Some(false),
+                _ => None,
+            },
+            "expected '@' or '.'",
+        )?;
+        let row_length = rows.first().map_or(0, Vec::len);
+        let col_length = rows.len();
+        let contents = rows.concat();
+
+        Ok(Self {
+            contents,
+            row_length,
+            col_length,
+        })
+    }
+
+    pub fn is_occupied(&self, i: usize, j: usize) -> bool {
+        if i >= self.col_length || j >= self.row_length {
+            return false;
+        }
+        self.contents
+            .get(i * self.row_length + j)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut bool> {
+        if i >= self.col_length || j >= self.row_length {
+            return None;
+        }
+        self.contents.get_mut(i * self.row_length + j)
+    }
+
+    pub fn get_neighbors(&self, i: usize, j: usize) -> impl Iterator<Item = (usize, usize)> {
+        let offsets = [
+            (-1, -1),
+            (-1, 0),
+            (-1, 1),
+            (0, -1),
+            (0, 1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+        ];
+        offsets.into_iter().filter_map(move |(i_offset, j_offset)| {
+            Some((
+                i.checked_add_signed(i_offset)?,
+                j.checked_add_signed(j_offset)?,
+            ))
+        })
+    }
+
+    /// A roll is accessible if fewer than four of its neighbors are occupied
+    pub fn is_accessible(&self, i: usize, j: usize) -> bool {
+        if !self.is_occupied(i, j) {
+            return false;
+        }
+        let count = self
+            .get_neighbors(i, j)
+            .filter(|neighbor| self.is_occupied(neighbor.0, neighbor.1))
+            .count();
+        count < 4
+    }
+
+    pub fn get_accessible(&self) -> impl Iterator<Item = (usize, usize)> {
+        iproduct!(0..self.col_length, 0..self.row_length).filter(|&(i, j)| self.is_accessible(i, j))
+    }
+
+    pub fn without(mut self, disallowed: impl Iterator<Item = (usize, usize)>) -> Self {
+        for (i, j) in disallowed {
+            if let Some(entry) = self.get_mut(i, j) {
+                *entry = false
+            }
+        }
+        self
+    }
+
+    /// Repeatedly removes the accessible rolls, counting how many are removed in total
+    pub fn count_removable(mut self) -> usize {
+        let mut count = 0;
+        loop {
+            let accessible_locations: Vec<_> = self.get_accessible().collect();
+            if accessible_locations.is_empty() {
+                return count;
+            }
+            count += accessible_locations.len();
+            self = self.without(accessible_locations.into_iter());
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Day4;
+
+inventory::submit! {
+    Puzzle::new::<Day4>(2025, 4, "Printing Department")
+        .with_generator::<Day4Generator>()
+        .with_reference::<Day4Naive>()
+}
+
+impl Solution for Day4 {
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../../examples/2025/day4.txt"),
+        params: &[],
+        part1: Some("13"),
+        part2: Some("43"),
+    }];
+
+    type Input = Grid;
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(Grid::new(input)?)
+    }
+
+    type Part1Output = usize;
+
+    fn part1(&self, grid: &Self::Input) -> Result<Self::Part1Output> {
+        let count = grid.get_accessible().count();
+        Ok(count)
+    }
+
+    type Part2Output = usize;
+    fn part2(&self, grid: &Self::Input) -> Result<Self::Part2Output> {
+        Ok(grid.clone().count_removable())
+    }
+}
+
+/// A random grid with each position holding a roll with the same chance
+pub struct Day4Generator {
+    width: usize,
+    height: usize,
+    density: f64,
+}
+
+impl Default for Day4Generator {
+    fn default() -> Self {
+        Self {
+            width: 140,
+            height: 140,
+            density: 0.65,
+        }
+    }
+}
+
+impl Generator for Day4Generator {
+    const SMALL: &'static [(&'static str, &'static str)] = &[("width", "12"), ("height", "12")];
+
+    fn knobs(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("width", self.width.to_string()),
+            ("height", self.height.to_string()),
+            ("density", self.density.to_string()),
+        ]
+    }
+
+    fn set_knob(&mut self, name: &str, value: &str) -> Result<()> {
+        match name {
+            "width" => self.width = parse_knob(name, value, 1..)?,
+            "height" => self.height = parse_knob(name, value, 1..)?,
+            "density" => self.density = parse_knob(name, value, 0.0..=1.0)?,
+            _ => bail!("unknown knob '{}'", name),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut StdRng) -> String {
+        (0..self.height)
+            .map(|_| {
+                let row: String = (0..self.width)
+                    .map(|_| {
+                        if rng.random_bool(self.density) {
+                            '@'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect();
+                row + "\n"
+            })
+            .collect()
+    }
+}
+
+/// Removes one roll at a time, as a reference for removing every accessible roll at once
+#[derive(Default)]
+pub struct Day4Naive;
+
+impl Solution for Day4Naive {
+    const EXAMPLES: &'static [Example] = Day4::EXAMPLES;
+
+    type Input = Grid;
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(Grid::new(input)?)
+    }
+
+    type Part1Output = usize;
+    fn part1(&self, grid: &Self::Input) -> Result<Self::Part1Output> {
+        let accessible = iproduct!(0..grid.col_length, 0..grid.row_length).filter(|&(i, j)| {
+            let occupied = iproduct!(-1..=1, -1..=1)
+                .filter(|&offset| offset != (0, 0))
+                .filter(|&(di, dj)| {
+                    let neighbor = (i as isize + di, j as isize + dj);
+                    neighbor.0 >= 0
+                        && neighbor.1 >= 0
+                        && grid.is_occupied(neighbor.0 as usize, neighbor.1 as usize)
+                })
+                .count();
+            grid.is_occupied(i, j) && occupied < 4
+        });
+        Ok(accessible.count())
+    }
+
+    type Part2Output = usize;
+    fn part2(&self, grid: &Self::Input) -> Result<Self::Part2Output> {
+        let mut grid = grid.clone();
+        let mut count = 0;
+        loop {
+            let Some(roll) = grid.get_accessible().next() else {
+                return Ok(count);
+            };
+            grid = grid.without(std::iter::once(roll));
+            count += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::check_examples;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day4>();
+        check_examples::<Day4Naive>();
+    }
+}
@@ -0,0 +1,218 @@
+#[derive(Default)]
+pub struct Day5;
+
+use crate::generate::{Generator, parse_knob};
+use crate::parse::{ParseError, Parser};
+use crate::registry::Puzzle;
+use crate::solution::{Example, Solution};
+use anyhow::{Context, Result, bail};
+use itertools::Itertools;
+use rand::RngExt;
+use rand::rngs::StdRng;
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+/// Merges overlapping and adjacent ranges, returning them sorted by start
+pub fn merge_overlapping(
+    ranges: impl Iterator<Item = RangeInclusive<usize>>,
+) -> impl Iterator<Item = RangeInclusive<usize>> {
+    ranges
+        .sorted_unstable_by_key(|r| *r.start())
+        .coalesce(|prev, curr| {
+            if *curr.start() <= prev.end().saturating_add(1) {
+                // Ranges overlap, merge them
+                let &new_end = prev.end().max(curr.end());
+                let merged = *prev.start()..=new_end;
+                Ok(merged)
+            } else {
+                // No overlap, keep them separate
+                Err((prev, curr))
+            }
+        })
+}
+
+pub fn contains<'a>(
+    ranges: impl IntoIterator<Item = &'a RangeInclusive<usize>>,
+    num: &usize,
+) -> bool {
+    ranges.into_iter().any(|range| range.contains(num))
+}
+
+pub fn parse_ranges(
+    fresh_ranges: &str,
+) -> impl Iterator<Item = Result<RangeInclusive<usize>, ParseError>> + '_ {
+    let parser = Parser::new(fresh_ranges);
+    fresh_ranges.lines().map(move |line| parser.range(line))
+}
+
+pub struct Inventory {
+    pub fresh_ranges: Vec<RangeInclusive<usize>>,
+    pub available_ids: Vec<usize>,
+}
+
+inventory::submit! {
+    Puzzle::new::<Day5>(2025, 5, "Cafeteria")
+        .with_generator::<Day5Generator>()
+        .with_reference::<Day5Naive>()
+}
+
+impl Solution for Day5 {
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../../examples/2025/day5.txt"),
+        params: &[],
+        part1: Some("3"),
+        part2: Some("14"),
+    }];
+
+    type Input = Inventory;
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let parser = Parser::new(input);
+        let [fresh_ranges, available_ids] = parser.exactly(
+            parser.sections(input),
+            input,
+            "sections separated by an empty line",
+        )?;
+        let fresh_ranges = parser.within(fresh_ranges, |fresh_ranges| {
+            parse_ranges(fresh_ranges).collect()
+        })?;
+        let available_ids = available_ids
+            .lines()
+            .map(|line| parser.number(line))
+            .collect::<Result<_, _>>()?;
+        Ok(Inventory {
+            fresh_ranges,
+            available_ids,
+        })
+    }
+
+    type Part1Output = usize;
+    fn part1(&self, inventory: &Self::Input) -> Result<Self::Part1Output> {
+        let fresh_ranges: Vec<_> =
+            merge_overlapping(inventory.fresh_ranges.iter().cloned()).collect();
+
+        let fresh_id_count = inventory
+            .available_ids
+            .iter()
+            .filter(|id| contains(&fresh_ranges, id))
+            .count();
+
+        Ok(fresh_id_count)
+    }
+
+    type Part2Output = usize;
+    fn part2(&self, inventory: &Self::Input) -> Result<Self::Part2Output> {
+        merge_overlapping(inventory.fresh_ranges.iter().cloned())
+            .try_fold(0usize, |count, range| {
+                (range.end() - range.start())
+                    .checked_add(1)?
+                    .checked_add(count)
+            })
+            .context("too many fresh IDs to count")
+    }
+}
+
+/// Random ranges of fresh IDs, which may overlap, followed by random available IDs
+pub struct Day5Generator {
+    ranges: usize,
+    ids: usize,
+    max_id: usize,
+    max_width: usize,
+}
+
+impl Default for Day5Generator {
+    fn default() -> Self {
+        Self {
+            ranges: 180,
+            ids: 1000,
+            max_id: 500_000_000_000_000,
+            max_width: 20_000_000_000_000,
+        }
+    }
+}
+
+impl Generator for Day5Generator {
+    const SMALL: &'static [(&'static str, &'static str)] = &[
+        ("ranges", "10"),
+        ("ids", "30"),
+        ("max_id", "500"),
+        ("max_width", "40"),
+    ];
+
+    fn knobs(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("ranges", self.ranges.to_string()),
+            ("ids", self.ids.to_string()),
+            ("max_id", self.max_id.to_string()),
+            ("max_width", self.max_width.to_string()),
+        ]
+    }
+
+    fn set_knob(&mut self, name: &str, value: &str) -> Result<()> {
+        // leave room for the ends of the widest ranges
+        let limit = usize::MAX / 4;
+        match name {
+            "ranges" => self.ranges = parse_knob(name, value, 1..)?,
+            "ids" => self.ids = parse_knob(name, value, 1..)?,
+            "max_id" => self.max_id = parse_knob(name, value, 1..=limit)?,
+            "max_width" => self.max_width = parse_knob(name, value, 0..=limit)?,
+            _ => bail!("unknown knob '{}'", name),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut StdRng) -> String {
+        let mut input = String::new();
+        for _ in 0..self.ranges {
+            let start = rng.random_range(1..=self.max_id);
+            let end = start + rng.random_range(0..=self.max_width);
+            input += &format!("{}-{}\n", start, end);
+        }
+        input.push('\n');
+        for _ in 0..self.ids {
+            input += &format!("{}\n", rng.random_range(1..=self.max_id));
+        }
+        input
+    }
+}
+
+/// Lists every fresh ID, as a reference for merging the ranges
+#[derive(Default)]
+pub struct Day5Naive;
+
+impl Solution for Day5Naive {
+    const EXAMPLES: &'static [Example] = Day5::EXAMPLES;
+
+    type Input = Inventory;
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Day5.parse(input)
+    }
+
+    type Part1Output = usize;
+    fn part1(&self, inventory: &Self::Input) -> Result<Self::Part1Output> {
+        let fresh = inventory.available_ids.iter().filter(|id| {
+            inventory
+                .fresh_ranges
+                .iter()
+                .any(|range| range.contains(id))
+        });
+        Ok(fresh.count())
+    }
+
+    type Part2Output = usize;
+    fn part2(&self, inventory: &Self::Input) -> Result<Self::Part2Output> {
+        let fresh: HashSet<_> = inventory.fresh_ranges.iter().cloned().flatten().collect();
+        Ok(fresh.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::check_examples;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day5>();
+        check_examples::<Day5Naive>();
+    }
+}
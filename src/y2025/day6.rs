@@ -0,0 +1,390 @@
+use crate::generate::{Generator, parse_knob};
+use crate::parse::{ParseContext, ParseError, Parser};
+use crate::registry::Puzzle;
+use crate::solution::{Example, Solution};
+use anyhow::{Context, Result, bail, ensure};
+use num_traits::{CheckedAdd, CheckedMul, One, Zero};
+use rand::RngExt;
+use rand::rngs::StdRng;
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::str::FromStr;
+
+pub enum Op {
+    Mul,
+    Add,
+}
+
+impl Op {
+    /// Applies the operation, or `None` if the result overflows
+    pub fn apply<T: CheckedAdd + CheckedMul>(&self, a: T, b: T) -> Option<T> {
+        match self {
+            Self::Add => a.checked_add(&b),
+            Self::Mul => a.checked_mul(&b),
+        }
+    }
+
+    pub fn identity<T: One + Zero>(&self) -> T {
+        match self {
+            Op::Mul => T::one(),
+            Op::Add => T::zero(),
+        }
+    }
+}
+
+impl FromStr for Op {
+    type Err = anyhow::Error;
+
+    fn from_str(op: &str) -> Result<Self> {
+        Ok(match op {
+            "+" => Op::Add,
+            "*" => Op::Mul,
+            _ => bail!("expected '+' or '*'"),
+        })
+    }
+}
+
+/// A worksheet of problems, read both by rows and by columns
+pub struct Worksheet {
+    pub operations: Vec<Op>,
+    /// The numbers on each line, read left to right
+    pub rows: Vec<Vec<usize>>,
+    /// The number written down each column of characters, or `None` between problems
+    pub columns: Vec<Option<usize>>,
+}
+
+fn parse_worksheet(input: &str) -> Result<Worksheet, ParseError> {
+    let parser = Parser::new(input);
+    let operations = input
+        .lines()
+        .last()
+        .at(input, input, "empty input")?
+        .split_whitespace()
+        .map(|op| op.parse().at(input, op, "invalid operation"))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let col_length = input.lines().count();
+    let number_lines = || input.lines().take(col_length - 1);
+    let rows = number_lines()
+        .map(|line| parser.spaced_numbers(line))
+        .collect::<Result<_, _>>()?;
+
+    // the digits of a column line up, so every line of numbers must be as long as the first
+    let row_length = number_lines().next().map_or(0, |line| line.chars().count());
+    if let Some(line) = number_lines().find(|line| line.chars().count() != row_length) {
+        return Err(parser.error(
+            line,
+            format!(
+                "expected {} characters like the first line, got {}",
+                row_length,
+                line.chars().count()
+            ),
+        ));
+    }
+    let chars: Vec<_> = number_lines().flat_map(str::chars).collect();
+    let columns = (0..row_length)
+        .map(|i| {
+            let column = chars
+                .iter()
+                .skip(i)
+                .step_by(row_length)
+                .collect::<String>();
+            let column = column.trim();
+            if column.is_empty() {
+                Ok(None)
+            } else {
+                // the column isn't a slice of the input, so point at its top instead
+                let first_line = number_lines().next().unwrap_or_default();
+                let top = first_line
+                    .get(i..i + 1)
+                    .unwrap_or(&first_line[first_line.len()..]);
+                column
+                    .parse()
+                    .map(Some)
+                    .at(input, top, "invalid number in column")
+            }
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Worksheet {
+        operations,
+        rows,
+        columns,
+    })
+}
+
+#[derive(Default)]
+pub struct Day6;
+
+inventory::submit! {
+    Puzzle::new::<Day6>(2025, 6, "Trash Compactor")
+        .with_generator::<Day6Generator>()
+        .with_reference::<Day6Naive>()
+}
+
+impl Solution for Day6 {
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../../examples/2025/day6.txt"),
+        params: &[],
+        part1: Some("4277556"),
+        part2: Some("3263827"),
+    }];
+
+    type Input = Worksheet;
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse_worksheet(input)?)
+    }
+
+    type Part1Output = usize;
+    fn part1(&self, worksheet: &Self::Input) -> Result<Self::Part1Output> {
+        let operations = &worksheet.operations;
+        let mut accumulations = operations.iter().map(Op::identity).collect::<Vec<_>>();
+        for (line, row) in worksheet.rows.iter().enumerate() {
+            ensure!(
+                row.len() <= operations.len(),
+                "line {} has {} numbers but there are only {} operations",
+                line + 1,
+                row.len(),
+                operations.len()
+            );
+            for (i, &num) in row.iter().enumerate() {
+                let acc = &mut accumulations[i];
+                *acc = operations[i].apply(*acc, num).context(TOO_LARGE)?;
+            }
+        }
+        grand_total(&accumulations)
+    }
+
+    type Part2Output = usize;
+    fn part2(&self, worksheet: &Self::Input) -> Result<Self::Part2Output> {
+        let operations = &worksheet.operations;
+        let problems: Vec<_> = worksheet
+            .columns
+            .split(Option::is_none)
+            .filter(|columns| !columns.is_empty())
+            .collect();
+        ensure!(
+            problems.len() <= operations.len(),
+            "there are {} problems but only {} operations",
+            problems.len(),
+            operations.len()
+        );
+        let accumulations = problems
+            .into_iter()
+            .zip(operations)
+            .map(|(columns, op)| {
+                columns
+                    .iter()
+                    .flatten()
+                    .try_fold(op.identity(), |acc, &num| op.apply(acc, num))
+                    .context(TOO_LARGE)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        grand_total(&accumulations)
+    }
+}
+
+const TOO_LARGE: &str = "an answer is too large to compute";
+
+fn grand_total(answers: &[usize]) -> Result<usize> {
+    answers
+        .iter()
+        .try_fold(0usize, |total, answer| total.checked_add(*answer))
+        .context(TOO_LARGE)
+}
+
+/// A random worksheet, where the numbers of each problem line up on the left or on the right
+pub struct Day6Generator {
+    problems: usize,
+    rows: usize,
+    max_digits: usize,
+}
+
+impl Default for Day6Generator {
+    fn default() -> Self {
+        Self {
+            problems: 1000,
+            rows: 4,
+            max_digits: 4,
+        }
+    }
+}
+
+impl Generator for Day6Generator {
+    const SMALL: &'static [(&'static str, &'static str)] = &[("problems", "10")];
+
+    fn knobs(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("problems", self.problems.to_string()),
+            ("rows", self.rows.to_string()),
+            ("max_digits", self.max_digits.to_string()),
+        ]
+    }
+
+    fn set_knob(&mut self, name: &str, value: &str) -> Result<()> {
+        // larger problems multiply to more than 64 bits
+        match name {
+            "problems" => self.problems = parse_knob(name, value, 1..)?,
+            "rows" => self.rows = parse_knob(name, value, 1..=4)?,
+            "max_digits" => self.max_digits = parse_knob(name, value, 1..=4)?,
+            _ => bail!("unknown knob '{}'", name),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut StdRng) -> String {
+        let mut lines = vec![Vec::new(); self.rows + 1];
+        for _ in 0..self.problems {
+            // a column can't have a gap between its digits, so the lengths
+            // have to rise and then fall from the top to the bottom of the problem
+            let mut lengths: Vec<_> = (0..self.rows)
+                .map(|_| rng.random_range(1..=self.max_digits))
+                .collect();
+            lengths.sort_unstable_by(|a, b| b.cmp(a));
+            let mut ordered = VecDeque::new();
+            for length in lengths {
+                if rng.random_bool(0.5) {
+                    ordered.push_front(length);
+                } else {
+                    ordered.push_back(length);
+                }
+            }
+            let numbers: Vec<String> = ordered
+                .into_iter()
+                .map(|length| {
+                    (0..length)
+                        .map(|_| char::from(b'0' + rng.random_range(1..=9)))
+                        .collect()
+                })
+                .collect();
+            let width = numbers.iter().map(String::len).max().unwrap_or_default();
+            let left_aligned = rng.random_bool(0.5);
+            for (line, number) in lines.iter_mut().zip(numbers) {
+                line.push(if left_aligned {
+                    format!("{:<width$}", number)
+                } else {
+                    format!("{:>width$}", number)
+                });
+            }
+            let op = if rng.random_bool(0.5) { "+" } else { "*" };
+            lines[self.rows].push(format!("{:<width$}", op));
+        }
+        lines.iter().map(|line| line.join(" ") + "\n").collect()
+    }
+}
+
+/// Reads the problems straight from the characters of the worksheet, as a reference for [`Worksheet`]
+#[derive(Default)]
+pub struct Day6Naive;
+
+/// The columns of each problem, which are separated by columns that are blank on every line
+fn problem_columns(lines: &[Vec<char>]) -> Vec<Range<usize>> {
+    let width = lines.iter().map(Vec::len).max().unwrap_or_default();
+    let is_blank = |i: usize| {
+        lines
+            .iter()
+            .all(|line| line.get(i).is_none_or(|c| c.is_whitespace()))
+    };
+    let mut problems = Vec::new();
+    let mut start = None;
+    for i in 0..=width {
+        match (start, i < width && !is_blank(i)) {
+            (None, true) => start = Some(i),
+            (Some(first), false) => {
+                problems.push(first..i);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    problems
+}
+
+/// Adds up the answers to the problems, reading each problem's numbers from its columns with `read`
+fn total(
+    lines: &[Vec<char>],
+    read: impl Fn(&[Vec<char>], Range<usize>) -> Vec<String>,
+) -> Result<usize> {
+    let (operations, numbers) = lines.split_last().context("empty worksheet")?;
+    problem_columns(lines)
+        .into_iter()
+        .map(|columns| {
+            let op: String = columns.clone().filter_map(|i| operations.get(i)).collect();
+            let op: Op = op.trim().parse()?;
+            read(numbers, columns)
+                .iter()
+                .filter(|number| !number.is_empty())
+                .try_fold(op.identity(), |acc, number| {
+                    op.apply(acc, number.parse::<usize>()?).context(TOO_LARGE)
+                })
+        })
+        .try_fold(0usize, |total, answer| {
+            total.checked_add(answer?).context(TOO_LARGE)
+        })
+}
+
+impl Solution for Day6Naive {
+    const EXAMPLES: &'static [Example] = Day6::EXAMPLES;
+
+    type Input = Vec<Vec<char>>;
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(|line| line.chars().collect()).collect())
+    }
+
+    type Part1Output = usize;
+    fn part1(&self, lines: &Self::Input) -> Result<Self::Part1Output> {
+        total(lines, |numbers, columns| {
+            numbers
+                .iter()
+                .map(|line| {
+                    let number: String = columns.clone().filter_map(|i| line.get(i)).collect();
+                    number.trim().to_string()
+                })
+                .collect()
+        })
+    }
+
+    type Part2Output = usize;
+    fn part2(&self, lines: &Self::Input) -> Result<Self::Part2Output> {
+        total(lines, |numbers, columns| {
+            columns
+                .map(|i| {
+                    numbers
+                        .iter()
+                        .filter_map(|line| line.get(i))
+                        .filter(|c| !c.is_whitespace())
+                        .collect()
+                })
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::check_examples;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day6>();
+        check_examples::<Day6Naive>();
+    }
+
+    #[test]
+    fn more_problems_than_operations_are_an_error() {
+        let worksheet = Day6.parse("1 2 3\n4 5 6\n+ *\n").unwrap();
+        assert!(Day6.part1(&worksheet).is_err());
+        assert!(Day6.part2(&worksheet).is_err());
+    }
+
+    #[test]
+    fn lines_of_different_lengths_are_an_error() {
+        let input = "12 3\r\n4 56\r\n7 8\r\n+ *\r\n";
+        let Err(err) = parse_worksheet(input) else {
+            panic!("a short line was accepted");
+        };
+        assert_eq!((err.line(), err.column()), (3, 1));
+        assert!(parse_worksheet(&input.replace("7 8", "7 89")).is_ok());
+    }
+}
@@ -0,0 +1,253 @@
+use crate::generate::{Generator, parse_knob};
+use crate::parse::{ParseError, Parser};
+use crate::registry::Puzzle;
+use crate::solution::{Example, Solution};
+use anyhow::{Context, Result, bail, ensure};
+use rand::RngExt;
+use rand::rngs::StdRng;
+use std::collections::BTreeSet;
+
+pub enum Cell {
+    Empty,
+    Splitter,
+    Start,
+}
+
+fn parse_manifold(input: &str) -> Result<Vec<Vec<Cell>>, ParseError> {
+    Parser::new(input).grid(
+        input,
+        |c| match c {
+            '^' => Some(Cell::Splitter),
+            '.' => Some(Cell::Empty),
+            'S' => Some(Cell::Start),
+            _ => None,
+        },
+        "expected '^', '.' or 'S'",
+    )
+}
+
+/// Traces the beams down the manifold, returning the number of splits
+/// and the number of timelines ending in each column
+pub fn solve(manifold: &[Vec<Cell>]) -> Result<(u64, Vec<u64>)> {
+    let width = manifold.iter().map(Vec::len).max().unwrap_or_default();
+    let mut beams = vec![0u64; width];
+    let mut count = 0;
+    for (row_index, row) in manifold.iter().enumerate() {
+        for (i, cell) in row.iter().enumerate() {
+            match cell {
+                Cell::Splitter if beams[i] > 0 => {
+                    let (Some(left), right) = (i.checked_sub(1), i + 1) else {
+                        bail!(
+                            "the splitter on row {} sends a beam off the left of the manifold",
+                            row_index + 1
+                        );
+                    };
+                    ensure!(
+                        right < width,
+                        "the splitter on row {} sends a beam off the right of the manifold",
+                        row_index + 1
+                    );
+                    count += 1;
+                    for side in [right, left] {
+                        beams[side] = beams[side]
+                            .checked_add(beams[i])
+                            .context("too many timelines to count")?;
+                    }
+                    beams[i] = 0;
+                }
+                Cell::Splitter | Cell::Empty => {}
+                Cell::Start => beams[i] = 1,
+            }
+        }
+    }
+    Ok((count, beams))
+}
+
+#[derive(Default)]
+pub struct Day7;
+
+inventory::submit! {
+    Puzzle::new::<Day7>(2025, 7, "Laboratories")
+        .with_generator::<Day7Generator>()
+        .with_reference::<Day7Naive>()
+}
+
+impl Solution for Day7 {
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../../examples/2025/day7.txt"),
+        params: &[],
+        part1: Some("21"),
+        part2: Some("40"),
+    }];
+
+    type Input = Vec<Vec<Cell>>;
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse_manifold(input)?)
+    }
+
+    type Part1Output = u64;
+
+    fn part1(&self, manifold: &Self::Input) -> Result<Self::Part1Output> {
+        let (count, _) = solve(manifold)?;
+        Ok(count)
+    }
+
+    type Part2Output = u64;
+    fn part2(&self, manifold: &Self::Input) -> Result<Self::Part2Output> {
+        let (_, beams) = solve(manifold)?;
+        beams
+            .into_iter()
+            .try_fold(0u64, u64::checked_add)
+            .context("too many timelines to count")
+    }
+}
+
+/// A random manifold with the splitters laid out in a triangle below the start, as in the real one
+pub struct Day7Generator {
+    width: usize,
+    levels: usize,
+    density: f64,
+}
+
+impl Default for Day7Generator {
+    fn default() -> Self {
+        Self {
+            width: 141,
+            levels: 70,
+            density: 0.5,
+        }
+    }
+}
+
+impl Generator for Day7Generator {
+    const SMALL: &'static [(&'static str, &'static str)] = &[("width", "21"), ("levels", "10")];
+
+    fn knobs(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("width", self.width.to_string()),
+            ("levels", self.levels.to_string()),
+            ("density", self.density.to_string()),
+        ]
+    }
+
+    fn set_knob(&mut self, name: &str, value: &str) -> Result<()> {
+        // the number of timelines can double at every level,
+        // so dense manifolds with many levels have too many to count
+        match name {
+            "width" => self.width = parse_knob(name, value, 3..)?,
+            "levels" => self.levels = parse_knob(name, value, 1..)?,
+            "density" => self.density = parse_knob(name, value, 0.0..=1.0)?,
+            _ => bail!("unknown knob '{}'", name),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut StdRng) -> String {
+        let start = self.width / 2;
+        let empty = ".".repeat(self.width) + "\n";
+        let mut input = empty.clone();
+        input.replace_range(start..start + 1, "S");
+        for level in 0..self.levels {
+            input += &empty;
+            // splitters are never next to each other or on the edges, where a beam would leave the manifold
+            let row: String = (0..self.width)
+                .map(|i| {
+                    let reachable =
+                        i.abs_diff(start) <= level && (i + level + start).is_multiple_of(2);
+                    let inside = i > 0 && i < self.width - 1;
+                    if reachable && inside && rng.random_bool(self.density) {
+                        '^'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            input += &(row + "\n");
+        }
+        input + &empty
+    }
+}
+
+/// Follows every beam and every timeline on its own, as a reference for [`solve`]
+#[derive(Default)]
+pub struct Day7Naive;
+
+/// The number of timelines for a particle entering the manifold at this position
+fn timelines(manifold: &[Vec<Cell>], row: usize, column: usize) -> u64 {
+    match manifold.get(row).and_then(|cells| cells.get(column)) {
+        None => 1,
+        Some(Cell::Splitter) => {
+            let left = column
+                .checked_sub(1)
+                .map_or(1, |left| timelines(manifold, row + 1, left));
+            left + timelines(manifold, row + 1, column + 1)
+        }
+        Some(_) => timelines(manifold, row + 1, column),
+    }
+}
+
+fn find_start(manifold: &[Vec<Cell>]) -> Result<(usize, usize)> {
+    manifold
+        .iter()
+        .enumerate()
+        .find_map(|(row, cells)| {
+            let column = cells.iter().position(|cell| matches!(cell, Cell::Start))?;
+            Some((row, column))
+        })
+        .context("the manifold has no start")
+}
+
+impl Solution for Day7Naive {
+    const EXAMPLES: &'static [Example] = Day7::EXAMPLES;
+
+    type Input = Vec<Vec<Cell>>;
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse_manifold(input)?)
+    }
+
+    type Part1Output = u64;
+    fn part1(&self, manifold: &Self::Input) -> Result<Self::Part1Output> {
+        let (start_row, start_column) = find_start(manifold)?;
+        let mut beams = BTreeSet::from([start_column]);
+        let mut splits = 0;
+        for row in &manifold[start_row + 1..] {
+            let mut next = BTreeSet::new();
+            for beam in beams {
+                if let Some(Cell::Splitter) = row.get(beam) {
+                    splits += 1;
+                    next.extend(beam.checked_sub(1));
+                    next.insert(beam + 1);
+                } else {
+                    next.insert(beam);
+                }
+            }
+            beams = next;
+        }
+        Ok(splits)
+    }
+
+    type Part2Output = u64;
+    fn part2(&self, manifold: &Self::Input) -> Result<Self::Part2Output> {
+        let (row, column) = find_start(manifold)?;
+        Ok(timelines(manifold, row + 1, column))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::check_examples;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day7>();
+        check_examples::<Day7Naive>();
+    }
+
+    #[test]
+    fn beams_split_off_the_edge_are_an_error() {
+        let manifold = Day7.parse("S..\n^..\n").unwrap();
+        assert!(Day7.part1(&manifold).is_err());
+        assert!(Day7.part2(&manifold).is_err());
+    }
+}
@@ -0,0 +1,293 @@
+use crate::generate::{Generator, parse_knob};
+use crate::parse::{ParseError, Parser};
+use crate::registry::Puzzle;
+use crate::solution::{Example, Solution, parse_param};
+use anyhow::{Context, Result, bail};
+use itertools::Itertools;
+use petgraph::unionfind::UnionFind;
+use rand::RngExt;
+use rand::rngs::StdRng;
+use std::str::FromStr;
+
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+    pub z: usize,
+}
+
+impl Point {
+    fn coords(&self) -> impl Iterator<Item = usize> {
+        [self.x, self.y, self.z].into_iter()
+    }
+    pub fn squared_distance(&self, other: &Self) -> usize {
+        self.coords()
+            .zip(other.coords())
+            .map(|(a, b)| {
+                let d = a.abs_diff(b);
+                d * d
+            })
+            .sum()
+    }
+}
+
+/// The largest coordinate whose squared distances still add up within a `usize`
+const MAX_COORDINATE: usize = 1 << 31;
+
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let parser = Parser::new(s);
+        let [x, y, z] = parser.exactly(parser.numbers(s, ',')?, s, "coordinates")?;
+        if [x, y, z].into_iter().any(|coord| coord > MAX_COORDINATE) {
+            return Err(parser.error(s, format!("coordinates must be at most {}", MAX_COORDINATE)));
+        }
+        Ok(Self { x, y, z })
+    }
+}
+
+pub fn parse_points(input: &str) -> Result<Vec<Point>, ParseError> {
+    let parser = Parser::new(input);
+    input
+        .lines()
+        .map(|line| parser.within(line, str::parse))
+        .collect()
+}
+
+/// Every pair of points with their indices, closest first
+pub fn sorted_pairs(points: &[Point]) -> impl Iterator<Item = ((usize, &Point), (usize, &Point))> {
+    points
+        .iter()
+        .enumerate()
+        .tuple_combinations()
+        .sorted_by_cached_key(|((_, p1), (_, p2))| p1.squared_distance(p2))
+}
+
+/// Connects the `num_connections` closest pairs of points, returning the size of each circuit
+pub fn circuit_sizes(points: &[Point], num_connections: usize) -> Vec<usize> {
+    let mut uf = UnionFind::new(points.len());
+    sorted_pairs(points)
+        .take(num_connections)
+        .for_each(|((id1, _), (id2, _))| {
+            uf.union(id1, id2);
+        });
+    uf.into_labeling()
+        .into_iter()
+        .counts()
+        .into_values()
+        .collect()
+}
+
+/// Connects the closest pairs of points until they form a single circuit,
+/// returning the pair joined by the final connection
+pub fn last_connection(points: &[Point]) -> Option<(&Point, &Point)> {
+    let mut uf = UnionFind::new(points.len());
+    sorted_pairs(points)
+        .filter_map(|((id1, p1), (id2, p2))| uf.union(id1, id2).then_some((p1, p2)))
+        .nth(points.len().checked_sub(2)?)
+}
+
+pub struct Day8 {
+    /// 10 for the example input
+    num_connections: usize,
+}
+
+impl Default for Day8 {
+    fn default() -> Self {
+        Self {
+            num_connections: 1000,
+        }
+    }
+}
+
+inventory::submit! {
+    Puzzle::new::<Day8>(2025, 8, "Playground")
+        .with_generator::<Day8Generator>()
+        .with_reference::<Day8Naive>()
+}
+
+impl Solution for Day8 {
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../../examples/2025/day8.txt"),
+        params: &[("num_connections", "10")],
+        part1: Some("40"),
+        part2: Some("25272"),
+    }];
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![("num_connections", self.num_connections.to_string())]
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<()> {
+        match name {
+            "num_connections" => self.num_connections = parse_param(name, value)?,
+            _ => bail!("unknown parameter '{}'", name),
+        }
+        Ok(())
+    }
+
+    type Input = Vec<Point>;
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse_points(input)?)
+    }
+
+    type Part1Output = usize;
+    fn part1(&self, points: &Self::Input) -> Result<Self::Part1Output> {
+        let product = circuit_sizes(points, self.num_connections)
+            .into_iter()
+            .k_largest(3)
+            .product();
+
+        Ok(product)
+    }
+
+    type Part2Output = usize;
+    fn part2(&self, points: &Self::Input) -> Result<Self::Part2Output> {
+        let (p1, p2) = last_connection(points).context("failed to connect all points")?;
+        p1.x.checked_mul(p2.x).context("the answer is too large")
+    }
+}
+
+/// Junction boxes at random positions
+pub struct Day8Generator {
+    boxes: usize,
+    max_coordinate: usize,
+}
+
+impl Default for Day8Generator {
+    fn default() -> Self {
+        Self {
+            boxes: 1000,
+            max_coordinate: 100_000,
+        }
+    }
+}
+
+impl Generator for Day8Generator {
+    const SMALL: &'static [(&'static str, &'static str)] =
+        &[("boxes", "30"), ("max_coordinate", "1000")];
+
+    fn knobs(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("boxes", self.boxes.to_string()),
+            ("max_coordinate", self.max_coordinate.to_string()),
+        ]
+    }
+
+    fn set_knob(&mut self, name: &str, value: &str) -> Result<()> {
+        match name {
+            // part 2 connects at least one pair
+            "boxes" => self.boxes = parse_knob(name, value, 2..)?,
+            // the squared distances have to fit in 64 bits
+            "max_coordinate" => self.max_coordinate = parse_knob(name, value, 0..=1_000_000_000)?,
+            _ => bail!("unknown knob '{}'", name),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut StdRng) -> String {
+        (0..self.boxes)
+            .map(|_| {
+                let [x, y, z] = [(); 3].map(|_| rng.random_range(0..=self.max_coordinate));
+                format!("{},{},{}\n", x, y, z)
+            })
+            .collect()
+    }
+}
+
+/// Searches a graph of the connections, as a reference for the union-find in [`circuit_sizes`]
+#[derive(Default)]
+pub struct Day8Naive(Day8);
+
+/// Every pair of indices, closest first, with ties kept in the order of the pairs
+fn pairs_by_distance(points: &[Point]) -> Vec<(usize, usize)> {
+    let mut pairs: Vec<_> = (0..points.len()).tuple_combinations().collect();
+    pairs.sort_by_key(|&(a, b)| points[a].squared_distance(&points[b]));
+    pairs
+}
+
+/// The size of each group of points joined by the connections
+fn circuits(num_points: usize, connections: &[(usize, usize)]) -> Vec<usize> {
+    let mut neighbors = vec![Vec::new(); num_points];
+    for &(a, b) in connections {
+        neighbors[a].push(b);
+        neighbors[b].push(a);
+    }
+    let mut seen = vec![false; num_points];
+    let mut sizes = Vec::new();
+    for start in 0..num_points {
+        if seen[start] {
+            continue;
+        }
+        seen[start] = true;
+        let mut stack = vec![start];
+        let mut size = 0;
+        while let Some(point) = stack.pop() {
+            size += 1;
+            for &neighbor in &neighbors[point] {
+                if !seen[neighbor] {
+                    seen[neighbor] = true;
+                    stack.push(neighbor);
+                }
+            }
+        }
+        sizes.push(size);
+    }
+    sizes
+}
+
+impl Solution for Day8Naive {
+    const EXAMPLES: &'static [Example] = Day8::EXAMPLES;
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        self.0.params()
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<()> {
+        self.0.set_param(name, value)
+    }
+
+    type Input = Vec<Point>;
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse_points(input)?)
+    }
+
+    type Part1Output = usize;
+    fn part1(&self, points: &Self::Input) -> Result<Self::Part1Output> {
+        let pairs = pairs_by_distance(points);
+        let connections = &pairs[..self.0.num_connections.min(pairs.len())];
+        let sizes = circuits(points.len(), connections);
+        Ok(sizes.into_iter().k_largest(3).product())
+    }
+
+    type Part2Output = usize;
+    fn part2(&self, points: &Self::Input) -> Result<Self::Part2Output> {
+        let pairs = pairs_by_distance(points);
+        (1..=pairs.len())
+            .find(|&n| circuits(points.len(), &pairs[..n]).len() == 1)
+            .map(|n| {
+                let (a, b) = pairs[n - 1];
+                points[a].x.checked_mul(points[b].x)
+            })
+            .context("failed to connect all points")?
+            .context("the answer is too large")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::check_examples;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day8>();
+        check_examples::<Day8Naive>();
+    }
+
+    #[test]
+    fn one_box_has_no_last_connection() {
+        let points = Day8::default().parse("1,2,3\n").unwrap();
+        assert!(Day8::default().part2(&points).is_err());
+    }
+}
@@ -0,0 +1,390 @@
+use crate::generate::{Generator, parse_knob};
+use crate::parse::{ParseError, Parser};
+use crate::registry::Puzzle;
+use crate::solution::{Example, Solution};
+use anyhow::{Context, Result, bail};
+use itertools::Itertools;
+use itertools::iproduct;
+use rand::RngExt;
+use rand::rngs::StdRng;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+}
+
+/// The largest coordinate for which areas, and coordinates doubled to find centres, fit in a `usize`
+const MAX_COORDINATE: usize = 1 << 31;
+
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let parser = Parser::new(s);
+        let [x, y] = parser.exactly(parser.numbers(s, ',')?, s, "coordinates")?;
+        if [x, y].into_iter().any(|coord| coord > MAX_COORDINATE) {
+            return Err(parser.error(s, format!("coordinates must be at most {}", MAX_COORDINATE)));
+        }
+        Ok(Self { x, y })
+    }
+}
+
+pub struct Rectangle {
+    //defined by two opposite corners
+    small_corner: Point, // the smaller x and y (closer to origin)
+    big_corner: Point,   // the bigger x and y (farther from origin)
+}
+
+impl Rectangle {
+    pub fn new(p1: &Point, p2: &Point) -> Self {
+        let small_corner = Point::new(p1.x.min(p2.x), p1.y.min(p2.y));
+        let big_corner = Point::new(p1.x.max(p2.x), p1.y.max(p2.y));
+        Self {
+            small_corner,
+            big_corner,
+        }
+    }
+
+    pub fn intersects_segment(&self, l1: &Point, l2: &Point) -> bool {
+        //returns true if self intersects the line segment formed by l1 and l2
+        let line_left = l1.x.min(l2.x);
+        let line_right = l1.x.max(l2.x);
+        let line_bottom = l1.y.min(l2.y);
+        let line_top = l1.y.max(l2.y);
+
+        let rect_left = self.small_corner.x;
+        let rect_right = self.big_corner.x;
+        let rect_bottom = self.small_corner.y;
+        let rect_top = self.big_corner.y;
+
+        // Check if they're separated on the x-axis
+        let separated_horizontally = line_right <= rect_left || rect_right <= line_left;
+
+        // Check if they're separated on the y-axis
+        let separated_vertically = line_top <= rect_bottom || rect_top <= line_bottom;
+
+        // They intersect if they're not separated on either axis
+        !separated_horizontally && !separated_vertically
+    }
+
+    pub fn is_valid(&self, perimeter: &[Point]) -> bool {
+        //a rectangle is valid if it only contains red and green tiles
+        //that is equivalent to the rectangle not intersecting the perimeter
+        //and lying inside it rather than in a dent of the loop
+        perimeter
+            .iter()
+            .circular_tuple_windows()
+            .all(|(l1, l2)| !self.intersects_segment(l1, l2))
+            && self.centre_is_inside(perimeter)
+    }
+
+    fn centre_is_inside(&self, perimeter: &[Point]) -> bool {
+        //doubling every coordinate puts the centre on a whole number
+        let x = self.small_corner.x + self.big_corner.x;
+        let y = self.small_corner.y + self.big_corner.y;
+        let edges = perimeter
+            .iter()
+            .circular_tuple_windows::<(_, _)>()
+            .map(|(a, b)| {
+                Rectangle::new(&Point::new(2 * a.x, 2 * a.y), &Point::new(2 * b.x, 2 * b.y))
+            });
+        let on_perimeter = edges.clone().any(|edge| {
+            (edge.small_corner.x..=edge.big_corner.x).contains(&x)
+                && (edge.small_corner.y..=edge.big_corner.y).contains(&y)
+        });
+        //the centre is inside if a line from it to the right crosses the perimeter an odd number of times
+        let crossings = edges
+            .filter(|edge| {
+                edge.small_corner.x == edge.big_corner.x
+                    && edge.small_corner.x > x
+                    && (edge.small_corner.y..edge.big_corner.y).contains(&y)
+            })
+            .count();
+        on_perimeter || !crossings.is_multiple_of(2)
+    }
+
+    pub fn area(&self) -> usize {
+        let length = self.big_corner.x - self.small_corner.x + 1;
+        let width = self.big_corner.y - self.small_corner.y + 1;
+        length * width
+    }
+}
+
+pub fn parse_points(input: &str) -> Result<Vec<Point>, ParseError> {
+    let parser = Parser::new(input);
+    input
+        .lines()
+        .map(|line| parser.within(line, str::parse))
+        .try_collect()
+}
+
+pub fn possible_rectangles(points: &[Point]) -> impl Iterator<Item = Rectangle> {
+    points
+        .iter()
+        .tuple_combinations()
+        .map(|(p1, p2)| Rectangle::new(p1, p2))
+}
+
+#[derive(Default)]
+pub struct Day9;
+
+inventory::submit! {
+    Puzzle::new::<Day9>(2025, 9, "Movie Theater")
+        .with_generator::<Day9Generator>()
+        .with_reference::<Day9Naive>()
+}
+
+impl Solution for Day9 {
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: include_str!("../../examples/2025/day9.txt"),
+        params: &[],
+        part1: Some("50"),
+        part2: Some("24"),
+    }];
+
+    type Input = Vec<Point>;
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse_points(input)?)
+    }
+
+    type Part1Output = usize;
+    fn part1(&self, points: &Self::Input) -> Result<Self::Part1Output> {
+        possible_rectangles(points)
+            .map(|rectangle| rectangle.area())
+            .max()
+            .context("no points found")
+    }
+
+    type Part2Output = usize;
+    fn part2(&self, points: &Self::Input) -> Result<Self::Part2Output> {
+        possible_rectangles(points)
+            .filter(|rectangle| rectangle.is_valid(points))
+            .map(|rectangle| rectangle.area())
+            .max()
+            .context("unable to find valid rectangle")
+    }
+}
+
+/// The red tiles at the corners of a random loop that never touches itself.
+///
+/// The loop goes around a blob of cells grown one at a time on a small grid,
+/// whose rows and columns are then spread out over the floor.
+pub struct Day9Generator {
+    cells: usize,
+    max_coordinate: usize,
+}
+
+impl Default for Day9Generator {
+    fn default() -> Self {
+        Self {
+            cells: 5000,
+            max_coordinate: 100_000,
+        }
+    }
+}
+
+impl Generator for Day9Generator {
+    const SMALL: &'static [(&'static str, &'static str)] =
+        &[("cells", "12"), ("max_coordinate", "60")];
+
+    fn knobs(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("cells", self.cells.to_string()),
+            ("max_coordinate", self.max_coordinate.to_string()),
+        ]
+    }
+
+    fn set_knob(&mut self, name: &str, value: &str) -> Result<()> {
+        match name {
+            "cells" => self.cells = parse_knob(name, value, 1..)?,
+            // the areas have to fit in 64 bits
+            "max_coordinate" => self.max_coordinate = parse_knob(name, value, 1..=1_000_000_000)?,
+            _ => bail!("unknown knob '{}'", name),
+        }
+        Ok(())
+    }
+
+    fn generate(&self, rng: &mut StdRng) -> String {
+        let blob = grow_blob(rng, self.cells);
+        let corners = outline(&blob);
+        let spread = |rng: &mut StdRng, lines: Vec<i64>| {
+            let lines: Vec<_> = lines.into_iter().sorted().dedup().collect();
+            // keep a gap between neighboring lines, so that no two edges of the loop touch
+            let max_gap = (self.max_coordinate / (lines.len() + 1)).max(2);
+            let mut position = 0;
+            let positions: HashMap<_, _> = lines
+                .into_iter()
+                .map(|line| {
+                    position += rng.random_range(2..=max_gap);
+                    (line, position)
+                })
+                .collect();
+            positions
+        };
+        let xs = spread(rng, corners.iter().map(|&(x, _)| x).collect());
+        let ys = spread(rng, corners.iter().map(|&(_, y)| y).collect());
+        corners
+            .iter()
+            .map(|(x, y)| format!("{},{}\n", xs[x], ys[y]))
+            .collect()
+    }
+}
+
+/// A 4-connected set of cells without holes, where no two cells touch only at a corner
+fn grow_blob(rng: &mut StdRng, size: usize) -> HashSet<(i64, i64)> {
+    let mut blob = HashSet::from([(0, 0)]);
+    let mut cells = vec![(0, 0)];
+    while blob.len() < size {
+        let (x, y) = cells[rng.random_range(0..cells.len())];
+        let [dx, dy] = [[1, 0], [0, 1], [-1, 0], [0, -1]][rng.random_range(0..4)];
+        let cell = (x + dx, y + dy);
+        if !blob.contains(&cell) && can_add(&blob, cell) {
+            blob.insert(cell);
+            cells.push(cell);
+        }
+    }
+    blob
+}
+
+/// Whether adding the cell keeps the blob free of holes and corner contacts,
+/// which holds when its neighbors in the blob are a single run around it
+fn can_add(blob: &HashSet<(i64, i64)>, (x, y): (i64, i64)) -> bool {
+    let around = [
+        (0, 1),
+        (1, 1),
+        (1, 0),
+        (1, -1),
+        (0, -1),
+        (-1, -1),
+        (-1, 0),
+        (-1, 1),
+    ]
+    .map(|(dx, dy)| blob.contains(&(x + dx, y + dy)));
+    let runs = (0..8)
+        .filter(|&i| !around[i] && around[(i + 1) % 8])
+        .count();
+    // a run of neighbors always includes a side, unless it is just a corner
+    let touches_side = (0..8).step_by(2).any(|i| around[i]);
+    runs == 1 && touches_side
+}
+
+/// The corners of the blob's outline in order, where the cell `(x, y)` spans from `x` to `x + 1`
+fn outline(blob: &HashSet<(i64, i64)>) -> Vec<(i64, i64)> {
+    // every edge of a cell that isn't shared with another cell, going counterclockwise around the blob
+    let mut next = HashMap::new();
+    for &(x, y) in blob {
+        let sides = [
+            ((x, y - 1), (x, y), (x + 1, y)),
+            ((x + 1, y), (x + 1, y), (x + 1, y + 1)),
+            ((x, y + 1), (x + 1, y + 1), (x, y + 1)),
+            ((x - 1, y), (x, y + 1), (x, y)),
+        ];
+        for (neighbor, from, to) in sides {
+            if !blob.contains(&neighbor) {
+                next.insert(from, to);
+            }
+        }
+    }
+    let start = *next.keys().min().expect("a blob has at least one cell");
+    let mut points = vec![start];
+    let mut point = next[&start];
+    while point != start {
+        points.push(point);
+        point = next[&point];
+    }
+    // only keep the points where the outline turns
+    points
+        .iter()
+        .circular_tuple_windows()
+        .filter(|&(a, b, c)| (b.0 - a.0, b.1 - a.1) != (c.0 - b.0, c.1 - b.1))
+        .map(|(_, &b, _)| b)
+        .collect()
+}
+
+/// Checks every tile of the rectangles, as a reference for [`Rectangle::is_valid`]
+#[derive(Default)]
+pub struct Day9Naive;
+
+/// Whether the tile is red or green, meaning on the loop or inside it
+fn is_red_or_green(x: usize, y: usize, corners: &[Point]) -> bool {
+    let edges = corners.iter().circular_tuple_windows::<(_, _)>();
+    let on_loop = edges.clone().any(|(a, b)| {
+        (a.x.min(b.x)..=a.x.max(b.x)).contains(&x) && (a.y.min(b.y)..=a.y.max(b.y)).contains(&y)
+    });
+    // a tile is inside when a line from it to the right crosses the loop an odd number of times
+    let crossings = edges
+        .filter(|(a, b)| a.x == b.x && a.x > x && (a.y.min(b.y)..a.y.max(b.y)).contains(&y))
+        .count();
+    on_loop || !crossings.is_multiple_of(2)
+}
+
+/// The area of every rectangle with red tiles in two opposite corners, largest first
+fn rectangles(corners: &[Point]) -> Vec<(usize, &Point, &Point)> {
+    let mut rectangles: Vec<_> = corners
+        .iter()
+        .tuple_combinations()
+        .map(|(a, b)| ((a.x.abs_diff(b.x) + 1) * (a.y.abs_diff(b.y) + 1), a, b))
+        .collect();
+    rectangles.sort_by_key(|&(area, _, _)| Reverse(area));
+    rectangles
+}
+
+impl Solution for Day9Naive {
+    const EXAMPLES: &'static [Example] = Day9::EXAMPLES;
+
+    type Input = Vec<Point>;
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(parse_points(input)?)
+    }
+
+    type Part1Output = usize;
+    fn part1(&self, corners: &Self::Input) -> Result<Self::Part1Output> {
+        let (area, _, _) = *rectangles(corners).first().context("no points found")?;
+        Ok(area)
+    }
+
+    type Part2Output = usize;
+    fn part2(&self, corners: &Self::Input) -> Result<Self::Part2Output> {
+        rectangles(corners)
+            .into_iter()
+            .find(|(_, a, b)| {
+                iproduct!(a.x.min(b.x)..=a.x.max(b.x), a.y.min(b.y)..=a.y.max(b.y))
+                    .all(|(x, y)| is_red_or_green(x, y, corners))
+            })
+            .map(|(area, _, _)| area)
+            .context("unable to find valid rectangle")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::check_examples;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day9>();
+        check_examples::<Day9Naive>();
+    }
+
+    #[test]
+    fn rectangles_in_a_notch_of_the_loop_are_invalid() {
+        // a U shape, whose notch is bordered by the loop but lies outside it
+        let points = Day9
+            .parse("0,0\n10,0\n10,10\n8,10\n8,2\n2,2\n2,10\n0,10\n")
+            .unwrap();
+        let notch = Rectangle::new(&Point::new(2, 10), &Point::new(8, 2));
+        assert!(!notch.is_valid(&points));
+        assert_eq!(Day9.part2(&points).unwrap(), 33);
+        assert_eq!(Day9Naive.part2(&points).unwrap(), 33);
+    }
+}